@@ -1,5 +1,8 @@
+pub mod alias;
 pub mod auth;
 pub mod friends;
+pub mod instances;
 pub mod invite;
+pub mod notifications;
 pub mod users;
 pub mod worlds;
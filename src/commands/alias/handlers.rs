@@ -0,0 +1,157 @@
+use crate::common::auth_client::AuthenticatedClient;
+use crate::config::Config;
+use anyhow::Result;
+
+/// Strip the optional leading `@` and validate an alias name
+fn parse_alias_name(name: &str) -> Result<String> {
+    let name = name.strip_prefix('@').unwrap_or(name);
+    if name.is_empty() {
+        return Err(anyhow::anyhow!("Alias name cannot be empty"));
+    }
+    if name.chars().any(char::is_whitespace) || name.starts_with('@') {
+        return Err(anyhow::anyhow!(
+            "Alias name '{}' must not contain whitespace or additional '@'",
+            name
+        ));
+    }
+    Ok(name.to_string())
+}
+
+/// Handle the Set action
+pub async fn handle_set_action(name: &str, user: &str, use_direct_id: bool) -> Result<()> {
+    let name = parse_alias_name(name)?;
+
+    // Aliases of aliases and user IDs need no lookup
+    let user_id = if let Some(alias) = user.strip_prefix('@') {
+        crate::common::user_operations::resolve_alias(alias)?
+    } else if crate::common::utils::is_valid_user_id(user) {
+        user.to_string()
+    } else if use_direct_id {
+        return Err(anyhow::anyhow!(
+            "Invalid user ID format when using --id flag. User IDs should start with 'usr_' or be 8 characters long (legacy format)."
+        ));
+    } else {
+        let auth_client = AuthenticatedClient::new().await?;
+        crate::common::user_operations::resolve_user_identifier(
+            auth_client.api_config(),
+            user,
+            false,
+        )
+        .await?
+    };
+
+    let mut config = Config::load()?;
+    match config.set_alias(&name, &user_id) {
+        Some(previous) if previous != user_id => {
+            println!("Alias @{name} now points to {user_id} (was {previous})")
+        }
+        _ => println!("Alias @{name} points to {user_id}"),
+    }
+    config.save()
+}
+
+/// Handle the List action
+pub fn handle_list_action(json: bool) -> Result<()> {
+    let config = Config::load()?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&config.aliases)?);
+        return Ok(());
+    }
+
+    if config.aliases.is_empty() {
+        println!("No aliases defined. Use 'vrcli alias set <name> <user>' to add one.");
+        return Ok(());
+    }
+
+    // Show the last known display name when the history store has one
+    let store = crate::history::HistoryStore::open().ok();
+    let name_width = config
+        .aliases
+        .keys()
+        .map(|name| unicode_width::UnicodeWidthStr::width(name.as_str()) + 1)
+        .max()
+        .unwrap_or(0)
+        + 2;
+
+    for (name, user_id) in &config.aliases {
+        let display_name = store
+            .as_ref()
+            .and_then(|store| store.latest_display_name(user_id).ok().flatten());
+        let alias = crate::common::utils::format_text_with_width(&format!("@{name}"), name_width);
+        match display_name {
+            Some(display_name) => println!("{alias}{user_id} ({display_name})"),
+            None => println!("{alias}{user_id}"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle the Rm action
+pub fn handle_rm_action(name: &str) -> Result<()> {
+    // Not validated like a new alias, so entries saved by older versions can be removed
+    let name = name.strip_prefix('@').unwrap_or(name);
+    if name.is_empty() {
+        return Err(anyhow::anyhow!("Alias name cannot be empty"));
+    }
+    let mut config = Config::load()?;
+
+    match config.remove_alias(name) {
+        Some(user_id) => {
+            config.save()?;
+            println!("Removed alias @{name} ({user_id})");
+            Ok(())
+        }
+        None => Err(anyhow::anyhow!("Alias '@{}' does not exist", name)),
+    }
+}
+
+/// Handle the Remembered action
+pub fn handle_remembered_action(json: bool) -> Result<()> {
+    let config = Config::load()?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&config.remembered_names)?
+        );
+        return Ok(());
+    }
+
+    if config.remembered_names.is_empty() {
+        println!(
+            "No remembered choices. They are saved when you pick a user for an ambiguous name."
+        );
+        return Ok(());
+    }
+
+    let name_width = config
+        .remembered_names
+        .keys()
+        .map(|name| unicode_width::UnicodeWidthStr::width(name.as_str()))
+        .max()
+        .unwrap_or(0)
+        + 2;
+
+    for (name, user_id) in &config.remembered_names {
+        let name = crate::common::utils::format_text_with_width(name, name_width);
+        println!("{name}{user_id}");
+    }
+
+    Ok(())
+}
+
+/// Handle the Forget action
+pub fn handle_forget_action(name: &str) -> Result<()> {
+    let mut config = Config::load()?;
+
+    match config.forget_name(name) {
+        Some(user_id) => {
+            config.save()?;
+            println!("Forgot the choice for '{name}' ({user_id})");
+            Ok(())
+        }
+        None => Err(anyhow::anyhow!("No remembered choice for '{}'", name)),
+    }
+}
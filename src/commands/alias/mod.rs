@@ -0,0 +1,14 @@
+mod handlers;
+
+use anyhow::Result;
+use vrcli::AliasAction;
+
+pub async fn handle_alias_command(action: AliasAction) -> Result<()> {
+    match action {
+        AliasAction::Set { name, user, id } => handlers::handle_set_action(&name, &user, id).await,
+        AliasAction::List { json } => handlers::handle_list_action(json),
+        AliasAction::Rm { name } => handlers::handle_rm_action(&name),
+        AliasAction::Remembered { json } => handlers::handle_remembered_action(json),
+        AliasAction::Forget { name } => handlers::handle_forget_action(&name),
+    }
+}
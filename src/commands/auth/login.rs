@@ -223,12 +223,18 @@ async fn handle_successful_login(
 ) -> Result<()> {
     println!("Authentication successful! Welcome, {display_name}");
 
-    let app_config = if let Some(cookie) = auth_cookie {
+    let fresh_config = if let Some(cookie) = auth_cookie {
         Config::new_cookie(cookie.to_string(), two_fa_cookie.map(|s| s.to_string()))
     } else {
         Config::new_password(username.to_string(), password.to_string())
     };
 
+    // Keep hooks and other settings from an existing config when re-logging in
+    let app_config = match Config::load() {
+        Ok(existing) => existing.with_credentials_from(fresh_config),
+        Err(_) => fresh_config,
+    };
+
     app_config.save()?;
 
     if auth_cookie.is_some() {
@@ -1,5 +1,5 @@
+use crate::common::paginator;
 use anyhow::Result;
-use futures::stream::{self, StreamExt, TryStreamExt};
 use std::collections::HashSet;
 use vrchatapi::apis;
 
@@ -22,31 +22,12 @@ pub async fn fetch_pages_parallel(
         })
         .unwrap_or(20); // Reasonable default max pages to avoid infinite fetching
 
-    // Build a stream of page offsets: 0, 60, 120, … up to max_pages
-    let offsets = (0..max_pages)
-        .map(move |i| i as i32 * page_size)
-        .collect::<Vec<_>>();
-
-    // Turn that into a concurrent stream of futures, with bounded concurrency
-    let friends_batches: Vec<Vec<vrchatapi::models::LimitedUserFriend>> = stream::iter(offsets)
-        .map(|offset| {
-            let cfg = api_config.clone();
-            async move {
-                // Small delay to avoid hammering the API too hard
-                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
-                apis::friends_api::get_friends(&cfg, Some(offset), Some(page_size), offline).await
-            }
-        })
-        .buffered(5) // At most 5 requests in flight at once
-        .take_while(|res| {
-            // Stop the stream when we get an empty page
-            futures::future::ready(match res {
-                Ok(batch) => !batch.is_empty(),
-                Err(_) => true, // Let error bubble up later
-            })
-        })
-        .try_collect() // Collect Vec<Vec<LimitedUserFriend>>
-        .await?;
+    let friends_batches = paginator::walk_pages(0, page_size, max_pages, |offset, n| async move {
+        apis::friends_api::get_friends(api_config, Some(offset), Some(n), offline)
+            .await
+            .map_err(anyhow::Error::from)
+    })
+    .await?;
 
     // Flatten batches into one Vec<LimitedUserFriend>
     let result = friends_batches.into_iter().flatten().collect::<Vec<_>>();
@@ -0,0 +1,195 @@
+use super::table_adapter::{percentage, FriendStatsTableItem, HistoryTableItem};
+use crate::common::content_policy::{self, ContentFilter};
+use crate::common::{command_utils::display_results, display_options::DisplayOptions};
+use crate::history::{FriendStats, HistoryStore};
+use anyhow::Result;
+use std::collections::HashSet;
+use vrchatapi::apis;
+
+/// Handle the History action
+pub async fn handle_history_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    identifier: &str,
+    use_direct_id: bool,
+    limit: usize,
+    content_filter: &ContentFilter,
+    json: bool,
+) -> Result<()> {
+    let user_id = crate::common::user_operations::resolve_user_identifier(
+        api_config,
+        identifier,
+        use_direct_id,
+    )
+    .await?;
+
+    let store = HistoryStore::open()?;
+    let mut entries = store.user_history(&user_id, Some(limit))?;
+    let hidden = content_filter
+        .mask_locations(api_config, &mut entries, |entry| &mut entry.location)
+        .await;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        content_policy::print_hidden_footer(hidden, "location(s)", json);
+        return Ok(());
+    }
+
+    let table_items: Vec<HistoryTableItem> = entries.iter().map(HistoryTableItem::new).collect();
+    let display_options = DisplayOptions::from_flags(true, false, true, true, true, true, false);
+
+    display_results(
+        &table_items,
+        &display_options,
+        &format!("No recorded history for {identifier}. Run 'vrcli friends list' or 'vrcli friends watch' to record friend states."),
+    )?;
+    content_policy::print_hidden_footer(hidden, "location(s)", json);
+    Ok(())
+}
+
+/// Handle the Stats action
+pub async fn handle_stats_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    identifier: Option<&str>,
+    use_direct_id: bool,
+    top: usize,
+    content_filter: &ContentFilter,
+    json: bool,
+) -> Result<()> {
+    let store = HistoryStore::open()?;
+
+    let Some(identifier) = identifier else {
+        let mut all_stats = store.all_stats()?;
+        all_stats.sort_by(|a, b| {
+            b.last_online
+                .cmp(&a.last_online)
+                .then_with(|| a.display_name.cmp(&b.display_name))
+        });
+        let hidden = drop_hidden_worlds(api_config, content_filter, &mut all_stats).await;
+
+        if json {
+            println!("{}", serde_json::to_string_pretty(&all_stats)?);
+            content_policy::print_hidden_footer(hidden, "world(s)", json);
+            return Ok(());
+        }
+
+        let table_items: Vec<FriendStatsTableItem> =
+            all_stats.iter().map(FriendStatsTableItem::new).collect();
+        let display_options =
+            DisplayOptions::from_flags(true, false, true, true, true, true, false);
+        display_results(
+            &table_items,
+            &display_options,
+            "No recorded friend history yet.",
+        )?;
+        content_policy::print_hidden_footer(hidden, "world(s)", json);
+        return Ok(());
+    };
+
+    let user_id = crate::common::user_operations::resolve_user_identifier(
+        api_config,
+        identifier,
+        use_direct_id,
+    )
+    .await?;
+
+    let Some(mut stats) = store.user_stats(&user_id)? else {
+        if json {
+            println!("null");
+        } else {
+            println!("No recorded history for {identifier}.");
+        }
+        return Ok(());
+    };
+
+    let hidden =
+        drop_hidden_worlds(api_config, content_filter, std::slice::from_mut(&mut stats)).await;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+    } else {
+        display_user_stats(api_config, &stats, top).await;
+    }
+    content_policy::print_hidden_footer(hidden, "world(s)", json);
+
+    Ok(())
+}
+
+/// Drop the worlds the content policy hides from the stats, returning how many were dropped
+async fn drop_hidden_worlds(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    content_filter: &ContentFilter,
+    all_stats: &mut [FriendStats],
+) -> usize {
+    let world_ids: HashSet<String> = all_stats
+        .iter()
+        .flat_map(|stats| stats.worlds.iter().map(|(world_id, _, _)| world_id.clone()))
+        .collect();
+    let hidden_worlds = content_filter.hidden_worlds(api_config, world_ids).await;
+
+    let mut hidden = 0;
+    for stats in all_stats.iter_mut() {
+        let before = stats.worlds.len();
+        stats
+            .worlds
+            .retain(|(world_id, _, _)| !hidden_worlds.contains(world_id));
+        hidden += before - stats.worlds.len();
+    }
+    hidden
+}
+
+/// Display statistics of a single friend in key: value format
+async fn display_user_stats(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    stats: &FriendStats,
+    top: usize,
+) {
+    use crate::common::utils::{format_platform_short, format_unix_timestamp};
+
+    println!("Name: {}", stats.display_name);
+    println!("ID: {}", stats.user_id);
+    println!(
+        "Observed: {} → {} ({} samples)",
+        format_unix_timestamp(stats.first_seen),
+        format_unix_timestamp(stats.last_seen),
+        stats.total_samples
+    );
+
+    match stats.last_online {
+        Some(last_online) => println!("Last Online: {}", format_unix_timestamp(last_online)),
+        None => println!("Last Online: Never observed online"),
+    }
+    println!(
+        "Online: {}% of observations",
+        percentage(stats.online_samples, stats.total_samples)
+    );
+
+    if !stats.platforms.is_empty() {
+        println!("Platforms:");
+        for (platform, samples) in &stats.platforms {
+            println!(
+                "  {}: {}%",
+                format_platform_short(platform),
+                percentage(*samples, stats.online_samples)
+            );
+        }
+    }
+
+    if !stats.worlds.is_empty() {
+        println!("Top Worlds:");
+        let top_worlds: Vec<_> = stats.worlds.iter().take(top).collect();
+        let names =
+            futures::future::join_all(top_worlds.iter().map(|(world_id, _, _)| async move {
+                match apis::worlds_api::get_world(api_config, world_id).await {
+                    Ok(world) => format!("{} ({world_id})", world.name),
+                    Err(_) => world_id.clone(),
+                }
+            }))
+            .await;
+        for ((_, samples, visits), name) in top_worlds.into_iter().zip(names) {
+            println!(
+                "  {name}: {}% of online observations, {visits} visit(s)",
+                percentage(*samples, stats.online_samples)
+            );
+        }
+    }
+}
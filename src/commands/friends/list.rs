@@ -1,5 +1,9 @@
 use super::{fetcher, sorting, table_adapter::FriendTableItem};
-use crate::common::{command_utils::display_results, display_options::DisplayOptions};
+use crate::common::{
+    command_utils::display_results,
+    content_policy::{self, ContentFilter},
+    display_options::DisplayOptions,
+};
 use anyhow::Result;
 
 /// Configuration for list action filter and sort options
@@ -10,6 +14,7 @@ pub struct ListFilterOptions {
     pub limit: Option<i32>,
     pub sort_method: String,
     pub reverse: bool,
+    pub show_all: bool,
 }
 
 /// Handle the List action
@@ -29,6 +34,14 @@ pub async fn handle_list_action(
         fetcher::fetch_all_friends_parallel(api_config, filter_options.limit).await?
     };
 
+    // Record observed states for `friends history` and `friends stats`
+    crate::history::record_observed_friends(&all_friends);
+
+    // A complete list also refreshes the cache used for display name resolution
+    if !filter_options.offline && !filter_options.online && filter_options.limit.is_none() {
+        crate::common::friend_cache::update_cache(&all_friends);
+    }
+
     // Apply sorting
     if let Some(sort_method_enum) = sorting::SortMethod::from_str(&filter_options.sort_method) {
         sorting::sort_friends(&mut all_friends, sort_method_enum, filter_options.reverse);
@@ -53,9 +66,21 @@ pub async fn handle_list_action(
         all_friends.truncate(limit as usize);
     }
 
+    // Hide the locations of friends in worlds the content policy hides; the world lookups
+    // are skipped when no location is shown
+    let hidden = if display_options.show_location || display_options.json {
+        ContentFilter::load(filter_options.show_all)?
+            .mask_locations(api_config, &mut all_friends, |friend| &mut friend.location)
+            .await
+    } else {
+        0
+    };
+
     // Convert to table items
     let table_items: Vec<FriendTableItem> = all_friends.iter().map(FriendTableItem::new).collect();
 
     // Use common display function
-    display_results(&table_items, &display_options, "No friends found.")
+    display_results(&table_items, &display_options, "No friends found.")?;
+    content_policy::print_hidden_footer(hidden, "location(s)", display_options.json);
+    Ok(())
 }
@@ -1,6 +1,7 @@
 mod add;
 mod display;
-mod fetcher;
+pub(crate) mod fetcher;
+mod history;
 mod list;
 mod remove;
 mod show;
@@ -8,12 +9,16 @@ mod sorting;
 mod status;
 mod table_adapter;
 mod utils;
+mod watch;
 
+use crate::commands::invite::report::ReportOutput;
 use crate::common::auth_client::AuthenticatedClient;
+use crate::common::content_policy::ContentFilter;
 use crate::common::display_options::DisplayOptions;
 use anyhow::Result;
 use list::ListFilterOptions;
 use vrcli::FriendsAction;
+use watch::WatchOptions;
 
 pub async fn handle_friends_command(action: FriendsAction) -> Result<()> {
     let auth_client = AuthenticatedClient::new().await?;
@@ -33,6 +38,7 @@ pub async fn handle_friends_command(action: FriendsAction) -> Result<()> {
             json,
             sort,
             reverse,
+            show_all,
             all,
             help: _,
         } => {
@@ -42,6 +48,7 @@ pub async fn handle_friends_command(action: FriendsAction) -> Result<()> {
                 limit,
                 sort_method: sort,
                 reverse,
+                show_all,
             };
 
             let display_options = DisplayOptions::from_flags(
@@ -59,8 +66,12 @@ pub async fn handle_friends_command(action: FriendsAction) -> Result<()> {
         FriendsAction::Get {
             identifier,
             id,
+            show_all,
             json,
-        } => show::handle_show_action(api_config, &identifier, id, json).await,
+        } => {
+            let content_filter = ContentFilter::load(show_all)?;
+            show::handle_show_action(api_config, &identifier, id, &content_filter, json).await
+        }
         FriendsAction::Add { identifier, id } => {
             add::handle_add_action(api_config, &identifier, id).await
         }
@@ -70,5 +81,74 @@ pub async fn handle_friends_command(action: FriendsAction) -> Result<()> {
         FriendsAction::Status { identifier, id } => {
             status::handle_status_action(api_config, &identifier, id).await
         }
+        FriendsAction::History {
+            identifier,
+            id,
+            limit,
+            show_all,
+            json,
+        } => {
+            let content_filter = ContentFilter::load(show_all)?;
+            history::handle_history_action(
+                api_config,
+                &identifier,
+                id,
+                limit,
+                &content_filter,
+                json,
+            )
+            .await
+        }
+        FriendsAction::Stats {
+            identifier,
+            id,
+            top,
+            show_all,
+            json,
+        } => {
+            let content_filter = ContentFilter::load(show_all)?;
+            history::handle_stats_action(
+                api_config,
+                identifier.as_deref(),
+                id,
+                top,
+                &content_filter,
+                json,
+            )
+            .await
+        }
+        FriendsAction::Watch {
+            interval,
+            json,
+            no_hooks,
+            no_notifications,
+            show_all,
+        } => {
+            let options = WatchOptions {
+                interval,
+                json,
+                run_hooks: !no_hooks,
+                notifications: !no_notifications,
+                content_filter: ContentFilter::load(show_all)?,
+            };
+            watch::handle_watch_action(api_config, options).await
+        }
+        FriendsAction::Join {
+            identifier,
+            id,
+            force_request,
+            verbose,
+            json,
+        } => {
+            crate::commands::invite::handlers::handle_invite_request_action(
+                api_config,
+                &identifier,
+                id,
+                None,
+                force_request,
+                ReportOutput { verbose, json },
+            )
+            .await
+        }
     }
 }
@@ -1,4 +1,5 @@
 use super::display::{display_friend_details, display_friend_json};
+use crate::common::content_policy::ContentFilter;
 use anyhow::Result;
 use vrchatapi::apis;
 
@@ -7,6 +8,7 @@ pub async fn handle_show_action(
     api_config: &vrchatapi::apis::configuration::Configuration,
     identifier: &str,
     use_direct_id: bool,
+    content_filter: &ContentFilter,
     json: bool,
 ) -> Result<()> {
     let user_id = crate::common::user_operations::resolve_user_identifier(
@@ -17,8 +19,11 @@ pub async fn handle_show_action(
     .await?;
 
     // Fetch user details
-    let user =
+    let mut user =
         crate::common::user_operations::fetch_user_by_resolved_id(api_config, &user_id).await?;
+    content_filter
+        .mask_user_location(api_config, &mut user)
+        .await;
 
     // Check friend status
     let friend_status = (apis::friends_api::get_friend_status(api_config, &user_id).await).ok();
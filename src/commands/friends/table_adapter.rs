@@ -1,4 +1,4 @@
-use crate::common::table::TableDisplayable;
+use crate::common::table::{TableColumnNames, TableDisplayable};
 
 /// Wrapper for LimitedUserFriend to implement TableDisplayable
 pub struct FriendTableItem<'a> {
@@ -56,3 +56,133 @@ impl TableDisplayable for FriendTableItem<'_> {
         self.friend.last_activity.as_deref()
     }
 }
+
+/// Wrapper for a recorded history span to implement TableDisplayable
+pub struct HistoryTableItem<'a> {
+    entry: &'a crate::history::HistoryEntry,
+    seen: String,
+}
+
+impl<'a> HistoryTableItem<'a> {
+    pub fn new(entry: &'a crate::history::HistoryEntry) -> Self {
+        let first = crate::common::utils::format_unix_timestamp(entry.first_seen);
+        let seen = if entry.first_seen == entry.last_seen {
+            first
+        } else {
+            let last = crate::common::utils::format_unix_timestamp(entry.last_seen);
+            format!("{first} → {last}")
+        };
+        Self { entry, seen }
+    }
+}
+
+impl TableDisplayable for HistoryTableItem<'_> {
+    fn display_name(&self) -> &str {
+        &self.entry.display_name
+    }
+
+    fn id(&self) -> Option<&str> {
+        Some(&self.entry.user_id)
+    }
+
+    fn status(&self) -> Option<String> {
+        Some(self.entry.status.clone())
+    }
+
+    fn platform(&self) -> Option<&str> {
+        Some(&self.entry.platform)
+    }
+
+    fn formatted_platform(&self) -> Option<String> {
+        Some(crate::common::utils::format_platform_short(
+            &self.entry.platform,
+        ))
+    }
+
+    fn location(&self) -> Option<&str> {
+        Some(&self.entry.location)
+    }
+
+    fn activity(&self) -> Option<&str> {
+        Some(&self.seen)
+    }
+
+    fn column_names(&self) -> TableColumnNames {
+        TableColumnNames {
+            activity: "Seen",
+            ..TableColumnNames::default()
+        }
+    }
+}
+
+/// Wrapper for aggregated friend statistics to implement TableDisplayable
+pub struct FriendStatsTableItem<'a> {
+    stats: &'a crate::history::FriendStats,
+    last_online: String,
+    online_share: String,
+}
+
+impl<'a> FriendStatsTableItem<'a> {
+    pub fn new(stats: &'a crate::history::FriendStats) -> Self {
+        let last_online = stats
+            .last_online
+            .map(crate::common::utils::format_unix_timestamp)
+            .unwrap_or_else(|| "Never".to_string());
+        let online_share = format!("{}%", percentage(stats.online_samples, stats.total_samples));
+        Self {
+            stats,
+            last_online,
+            online_share,
+        }
+    }
+}
+
+impl TableDisplayable for FriendStatsTableItem<'_> {
+    fn display_name(&self) -> &str {
+        &self.stats.display_name
+    }
+
+    fn id(&self) -> Option<&str> {
+        Some(&self.stats.user_id)
+    }
+
+    fn status(&self) -> Option<String> {
+        Some(self.online_share.clone())
+    }
+
+    fn platform(&self) -> Option<&str> {
+        self.stats.platforms.first().map(|(p, _)| p.as_str())
+    }
+
+    fn formatted_platform(&self) -> Option<String> {
+        self.platform()
+            .map(crate::common::utils::format_platform_short)
+    }
+
+    fn location(&self) -> Option<&str> {
+        self.stats.worlds.first().map(|(w, _, _)| w.as_str())
+    }
+
+    fn activity(&self) -> Option<&str> {
+        Some(&self.last_online)
+    }
+
+    fn column_names(&self) -> TableColumnNames {
+        TableColumnNames {
+            status: "Online",
+            platform: "Platform",
+            location: "Top World",
+            activity: "Last Online",
+            ..TableColumnNames::default()
+        }
+    }
+}
+
+/// Integer percentage of part in total
+pub fn percentage(part: i64, total: i64) -> i64 {
+    if total == 0 {
+        0
+    } else {
+        part * 100 / total
+    }
+}
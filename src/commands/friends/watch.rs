@@ -0,0 +1,159 @@
+use super::fetcher;
+use crate::common::content_policy::ContentFilter;
+use crate::common::events::{self, Event};
+use crate::common::hooks;
+use crate::config::{Config, HookConfig};
+use crate::history::{self, FriendObservation};
+use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use vrchatapi::apis;
+
+/// Configuration for the watch action
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    pub interval: u64,
+    pub json: bool,
+    pub run_hooks: bool,
+    pub notifications: bool,
+    /// Applied to the locations in events and hooks; world lookups are reused across polls
+    pub content_filter: ContentFilter,
+}
+
+/// Previously observed state used to detect changes between polls
+#[derive(Default)]
+struct WatchState {
+    friends: Option<HashMap<String, vrchatapi::models::LimitedUserFriend>>,
+    seen_notifications: Option<HashSet<String>>,
+}
+
+/// Handle the Watch action: poll friends and notifications and emit change events
+pub async fn handle_watch_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    options: WatchOptions,
+) -> Result<()> {
+    let hooks: Vec<HookConfig> = if options.run_hooks {
+        Config::load()?.hooks
+    } else {
+        Vec::new()
+    };
+
+    for hook in &hooks {
+        if !events::EventKind::all_names().contains(&hook.event.as_str()) {
+            eprintln!(
+                "Warning: Hook for unknown event '{}'. Available events: {}",
+                hook.event,
+                events::EventKind::all_names().join(", ")
+            );
+        }
+    }
+
+    if !options.json {
+        eprintln!(
+            "👀 Watching friends every {}s ({} hook(s) configured). Press Ctrl+C to stop.",
+            options.interval,
+            hooks.len()
+        );
+    }
+
+    let mut state = WatchState::default();
+    let interval = Duration::from_secs(options.interval.max(1));
+
+    loop {
+        match poll_once(api_config, &options, &mut state).await {
+            Ok(events) => {
+                for event in events {
+                    emit_event(&event, options.json)?;
+                    hooks::run_matching_hooks(&hooks, &event).await;
+                }
+            }
+            Err(e) => eprintln!("Warning: Failed to poll VRChat API: {e}"),
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(interval) => {}
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    Ok(())
+}
+
+/// Fetch the current state once and return the events since the previous poll
+async fn poll_once(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    options: &WatchOptions,
+    state: &mut WatchState,
+) -> Result<Vec<Event>> {
+    let timestamp = chrono::Utc::now().to_rfc3339();
+    let mut events = Vec::new();
+
+    // Online friends only; anybody missing from this list is treated as offline
+    let mut online = fetcher::fetch_pages_parallel(api_config, Some(false), None).await?;
+
+    // The history keeps real locations; events and hooks only see what the policy allows
+    let mut observations: Vec<FriendObservation> =
+        online.iter().map(FriendObservation::from).collect();
+    options
+        .content_filter
+        .mask_locations(api_config, &mut online, |friend| &mut friend.location)
+        .await;
+    let current: HashMap<_, _> = online.into_iter().map(|f| (f.id.clone(), f)).collect();
+
+    // The first poll only establishes a baseline
+    if let Some(previous) = &state.friends {
+        // Friends that dropped out of the online list are recorded as offline
+        observations.extend(
+            previous
+                .values()
+                .filter(|f| !current.contains_key(&f.id))
+                .map(|f| FriendObservation {
+                    location: "offline".to_string(),
+                    ..FriendObservation::from(f)
+                }),
+        );
+        events.extend(events::diff_friends(previous, &current, &timestamp));
+    }
+    history::record_observations(&observations);
+    state.friends = Some(current);
+
+    if options.notifications {
+        let notifications = apis::notifications_api::get_notifications(
+            api_config,
+            None,
+            None,
+            None,
+            None,
+            Some(100),
+            None,
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch notifications: {}", e))?;
+
+        let ids: HashSet<String> = notifications.iter().map(|n| n.id.clone()).collect();
+        if let Some(seen) = &state.seen_notifications {
+            events.extend(
+                notifications
+                    .iter()
+                    .filter(|n| !seen.contains(&n.id))
+                    .map(|n| Event::from_notification(n, &timestamp)),
+            );
+        }
+        state
+            .seen_notifications
+            .get_or_insert_with(HashSet::new)
+            .extend(ids);
+    }
+
+    Ok(events)
+}
+
+/// Print an event either as a JSON line or as human-readable text
+fn emit_event(event: &Event, json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string(event)?);
+    } else {
+        println!("[{}] {}", event.timestamp, event.describe());
+    }
+    Ok(())
+}
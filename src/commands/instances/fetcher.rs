@@ -0,0 +1,65 @@
+use anyhow::Result;
+use vrchatapi::apis::instances_api;
+
+/// Convert an instances API error into a user-friendly message
+fn instance_error<T>(location: &str, e: vrchatapi::apis::Error<T>) -> anyhow::Error {
+    match e {
+        vrchatapi::apis::Error::ResponseError(ref response_content) => {
+            if response_content.status == 404 {
+                return anyhow::anyhow!("No instance found at '{}'", location);
+            }
+            anyhow::anyhow!(
+                "Failed to fetch instance '{}' - HTTP {}",
+                location,
+                response_content.status
+            )
+        }
+        _ => anyhow::anyhow!("Failed to fetch instance '{}' - {}", location, e),
+    }
+}
+
+/// Fetch an instance by world and instance ID
+pub async fn fetch_instance(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    world_id: &str,
+    instance_id: &str,
+) -> Result<vrchatapi::models::Instance> {
+    instances_api::get_instance(api_config, world_id, instance_id)
+        .await
+        .map_err(|e| instance_error(&format!("{world_id}:{instance_id}"), e))
+}
+
+/// Fetch the short name of an instance, falling back to its secure name
+pub async fn fetch_short_name(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    world_id: &str,
+    instance_id: &str,
+) -> Result<String> {
+    let response = instances_api::get_short_name(api_config, world_id, instance_id)
+        .await
+        .map_err(|e| instance_error(&format!("{world_id}:{instance_id}"), e))?;
+
+    Ok(response
+        .short_name
+        .flatten()
+        .unwrap_or(response.secure_name))
+}
+
+/// Create an instance, returning it as registered by the API
+pub async fn create_instance(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    request: vrchatapi::models::CreateInstanceRequest,
+) -> Result<vrchatapi::models::Instance> {
+    let world_id = request.world_id.clone();
+    instances_api::create_instance(api_config, request)
+        .await
+        .map_err(|e| match e {
+            vrchatapi::apis::Error::ResponseError(ref response_content) => anyhow::anyhow!(
+                "Failed to create an instance of '{}' - HTTP {}: {}",
+                world_id,
+                response_content.status,
+                response_content.content
+            ),
+            _ => anyhow::anyhow!("Failed to create an instance of '{}' - {}", world_id, e),
+        })
+}
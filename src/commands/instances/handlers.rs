@@ -0,0 +1,274 @@
+use super::fetcher;
+use crate::common::auth_client::AuthenticatedClient;
+use crate::common::content_policy::ContentFilter;
+use crate::common::location::{self, InstanceAccess, InstanceLocation};
+use crate::common::utils::format_platform_short;
+use anyhow::Result;
+use vrchatapi::apis;
+use vrchatapi::models::{CreateInstanceRequest, GroupAccessType, InstanceRegion, InstanceType};
+
+/// Base URL of VRChat's instance short links
+const SHORT_LINK_BASE: &str = "https://vrch.at";
+
+/// Handle the Get action
+pub async fn handle_get_action(
+    auth_client: &AuthenticatedClient,
+    location: &str,
+    content_filter: &ContentFilter,
+    json: bool,
+) -> Result<()> {
+    let api_config = auth_client.api_config();
+    let parsed = location::require_instance(location, "That location is")?;
+    let instance =
+        fetcher::fetch_instance(api_config, &parsed.world_id, &parsed.instance_id).await?;
+    content_filter.ensure_allowed(&instance.world)?;
+
+    let owner_id = instance.owner_id.clone().flatten();
+    let owner_name = match &owner_id {
+        Some(owner_id) => owner_display_name(api_config, owner_id).await,
+        None => None,
+    };
+    let join = join_status(&instance, &parsed, auth_client.current_user());
+
+    let mut platforms = vec![
+        (
+            format_platform_short("standalonewindows"),
+            instance.platforms.standalonewindows,
+        ),
+        (format_platform_short("android"), instance.platforms.android),
+    ];
+    if let Some(ios) = instance.platforms.ios {
+        platforms.push((format_platform_short("ios"), ios));
+    }
+
+    if json {
+        let platform_counts: serde_json::Map<String, serde_json::Value> = platforms
+            .iter()
+            .map(|(platform, count)| (platform.clone(), serde_json::json!(count)))
+            .collect();
+        let json_obj = serde_json::json!({
+            "location": instance.location,
+            "world_id": instance.world_id,
+            "world_name": instance.world.name,
+            "name": instance.name,
+            "type": parsed.access.to_string(),
+            "region": instance.region.to_string(),
+            "owner_id": owner_id,
+            "owner_name": owner_name,
+            "capacity": instance.capacity,
+            "user_count": instance.n_users,
+            "platforms": platform_counts,
+            "full": instance.full,
+            "queue_enabled": instance.queue_enabled,
+            "join": join,
+        });
+        println!("{}", serde_json::to_string_pretty(&json_obj)?);
+        return Ok(());
+    }
+
+    println!("World: {} ({})", instance.world.name, instance.world_id);
+    println!("Instance: {}", instance.name);
+    println!("Location: {}", instance.location);
+    println!("Type: {}", parsed.access);
+    println!("Region: {}", instance.region);
+    match (&owner_id, &owner_name) {
+        (Some(id), Some(name)) => println!("Owner: {name} ({id})"),
+        (Some(id), None) => println!("Owner: {id}"),
+        _ => {}
+    }
+    println!("Users: {}/{}", instance.n_users, instance.capacity);
+    for (platform, count) in &platforms {
+        println!("  {platform}: {count}");
+    }
+    println!("Full: {}", if instance.full { "Yes" } else { "No" });
+    println!("Can Join: {join}");
+
+    Ok(())
+}
+
+/// Handle the ShortName action
+pub async fn handle_short_name_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    location: &str,
+) -> Result<()> {
+    let parsed = location::require_instance(location, "That location is")?;
+    let short_name =
+        fetcher::fetch_short_name(api_config, &parsed.world_id, &parsed.instance_id).await?;
+    println!("{SHORT_LINK_BASE}/{short_name}");
+    Ok(())
+}
+
+/// Configuration for creating an instance
+#[derive(Debug, Clone)]
+pub struct InstanceCreateOptions {
+    pub world_id: String,
+    pub instance_type: String,
+    pub region: String,
+    pub group_id: Option<String>,
+    pub queue: bool,
+    pub age_gate: bool,
+    pub invite_self: bool,
+}
+
+/// Handle the Create action
+pub async fn handle_create_action(
+    auth_client: &AuthenticatedClient,
+    options: InstanceCreateOptions,
+    json: bool,
+) -> Result<()> {
+    let api_config = auth_client.api_config();
+    let owner_id = auth_client.current_user().map(|user| user.id.clone());
+    let request = build_create_request(&options, owner_id)?;
+
+    let instance = fetcher::create_instance(api_config, request).await?;
+    let created = location::require_instance(&instance.location, "The new instance is")?;
+
+    if options.invite_self {
+        apis::invite_api::invite_myself_to(api_config, &created.world_id, &created.instance_id)
+            .await
+            .map_err(|e| anyhow::anyhow!("Created {} but failed to invite you: {}", created, e))?;
+    }
+
+    if json {
+        let json_obj = serde_json::json!({
+            "location": created.location(),
+            "world_id": created.world_id,
+            "instance_id": created.instance_id,
+            "type": created.access.to_string(),
+            "region": instance.region.to_string(),
+            "launch_url": created.launch_url(),
+            "web_url": created.web_url(),
+            "invited": options.invite_self,
+        });
+        println!("{}", serde_json::to_string_pretty(&json_obj)?);
+        return Ok(());
+    }
+
+    println!("Location: {created}");
+    println!("Launch: {}", created.launch_url());
+    println!("Web: {}", created.web_url());
+    if options.invite_self {
+        println!("✅ Sent yourself an invite");
+    }
+
+    Ok(())
+}
+
+/// Translate the command line options into an instance creation request
+fn build_create_request(
+    options: &InstanceCreateOptions,
+    user_id: Option<String>,
+) -> Result<CreateInstanceRequest> {
+    if !options.world_id.starts_with("wrld_") {
+        return Err(anyhow::anyhow!(
+            "Invalid world ID '{}': world IDs start with 'wrld_'",
+            options.world_id
+        ));
+    }
+
+    let access = InstanceAccess::from_type_name(&options.instance_type)?;
+    let region: InstanceRegion =
+        serde_json::from_value(serde_json::Value::String(options.region.clone()))
+            .map_err(|_| anyhow::anyhow!("Unknown region '{}'", options.region))?;
+
+    let (instance_type, owner_id) = if access.is_group() {
+        let group_id = options.group_id.clone().ok_or_else(|| {
+            anyhow::anyhow!("Group instances need the owning group: pass --group grp_...")
+        })?;
+        (InstanceType::Group, Some(group_id))
+    } else {
+        if options.group_id.is_some() {
+            return Err(anyhow::anyhow!(
+                "--group only applies to group, group+ and group-public instances"
+            ));
+        }
+        let instance_type = match access {
+            InstanceAccess::Public => InstanceType::Public,
+            InstanceAccess::FriendsPlus => InstanceType::Hidden,
+            InstanceAccess::Friends => InstanceType::Friends,
+            _ => InstanceType::Private,
+        };
+        // Every non-public instance belongs to the user who created it
+        let owner_id = if access == InstanceAccess::Public {
+            None
+        } else {
+            Some(user_id.ok_or_else(|| {
+                anyhow::anyhow!("Could not determine your user ID to own the instance")
+            })?)
+        };
+        (instance_type, owner_id)
+    };
+
+    let mut request = CreateInstanceRequest::new(options.world_id.clone(), instance_type, region);
+    request.owner_id = Some(owner_id);
+    request.group_access_type = match access {
+        InstanceAccess::Group => Some(GroupAccessType::Members),
+        InstanceAccess::GroupPlus => Some(GroupAccessType::Plus),
+        InstanceAccess::GroupPublic => Some(GroupAccessType::Public),
+        _ => None,
+    };
+    if access == InstanceAccess::InvitePlus {
+        request.can_request_invite = Some(true);
+    }
+    if options.queue {
+        request.queue_enabled = Some(true);
+    }
+    if options.age_gate {
+        request.age_gate = Some(true);
+    }
+
+    Ok(request)
+}
+
+/// Look up a user owner's display name; group owners and failures yield None
+async fn owner_display_name(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    owner_id: &str,
+) -> Option<String> {
+    if !owner_id.starts_with("usr_") {
+        return None;
+    }
+    apis::users_api::get_user(api_config, owner_id)
+        .await
+        .ok()
+        .map(|user| user.display_name)
+}
+
+/// Describe whether the current user can join the instance
+fn join_status(
+    instance: &vrchatapi::models::Instance,
+    parsed: &InstanceLocation,
+    current_user: Option<&vrchatapi::models::CurrentUser>,
+) -> String {
+    if instance.full {
+        return if instance.queue_enabled {
+            "Queue (instance is full)".to_string()
+        } else {
+            "No (instance is full)".to_string()
+        };
+    }
+
+    let owner_id = parsed.owner_id.as_deref();
+    if let Some(user) = current_user {
+        if owner_id == Some(user.id.as_str()) {
+            return "Yes (you own it)".to_string();
+        }
+    }
+    let owner_is_friend = current_user
+        .zip(owner_id)
+        .is_some_and(|(user, owner_id)| user.friends.iter().any(|id| id == owner_id));
+
+    match parsed.access {
+        InstanceAccess::Public | InstanceAccess::GroupPublic => "Yes".to_string(),
+        InstanceAccess::Friends | InstanceAccess::FriendsPlus if owner_is_friend => {
+            "Yes".to_string()
+        }
+        InstanceAccess::Friends => "No (owner's friends only)".to_string(),
+        InstanceAccess::FriendsPlus => "Only if you are friends with someone inside".to_string(),
+        InstanceAccess::InvitePlus => "Request an invite".to_string(),
+        InstanceAccess::Invite => "No (invite only)".to_string(),
+        InstanceAccess::Group | InstanceAccess::GroupPlus => {
+            "Only as a member of the group".to_string()
+        }
+    }
+}
@@ -0,0 +1,47 @@
+mod fetcher;
+mod handlers;
+
+use crate::common::auth_client::AuthenticatedClient;
+use crate::common::content_policy::ContentFilter;
+use anyhow::Result;
+use handlers::InstanceCreateOptions;
+use vrcli::InstancesAction;
+
+pub async fn handle_instances_command(action: InstancesAction) -> Result<()> {
+    let auth_client = AuthenticatedClient::new().await?;
+
+    match action {
+        InstancesAction::Get {
+            location,
+            show_all,
+            json,
+        } => {
+            let content_filter = ContentFilter::load(show_all)?;
+            handlers::handle_get_action(&auth_client, &location, &content_filter, json).await
+        }
+        InstancesAction::ShortName { location } => {
+            handlers::handle_short_name_action(auth_client.api_config(), &location).await
+        }
+        InstancesAction::Create {
+            world_id,
+            r#type,
+            region,
+            group,
+            queue,
+            age_gate,
+            invite,
+            json,
+        } => {
+            let options = InstanceCreateOptions {
+                world_id,
+                instance_type: r#type,
+                region,
+                group_id: group,
+                queue,
+                age_gate,
+                invite_self: invite,
+            };
+            handlers::handle_create_action(&auth_client, options, json).await
+        }
+    }
+}
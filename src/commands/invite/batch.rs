@@ -0,0 +1,195 @@
+use crate::common::favorites;
+use crate::common::friend_filter::FriendFilter;
+use crate::common::location::{self, Location};
+use anyhow::Result;
+use futures::stream::{self, StreamExt};
+use std::collections::HashSet;
+use std::time::Duration;
+use vrchatapi::apis;
+use vrchatapi::models::{FavoriteType, InviteRequest};
+
+/// Minimum delay between the starts of two invites so a batch does not burst into the rate limit
+const INVITE_PACING: Duration = Duration::from_millis(250);
+
+/// Attempts per recipient when the API answers 429 Too Many Requests
+const MAX_ATTEMPTS: u32 = 3;
+
+/// Where the recipients of a batch invite come from
+#[derive(Debug, Clone)]
+pub struct RecipientOptions {
+    pub users: Vec<String>,
+    pub use_direct_id: bool,
+    pub favorite_group: Option<String>,
+    pub online_friends_filter: Option<String>,
+}
+
+/// A resolved invite recipient
+#[derive(Debug, Clone)]
+struct Recipient {
+    user_id: String,
+    label: String,
+}
+
+/// Handle sending the same invite to many recipients
+pub async fn handle_batch_invite_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    options: RecipientOptions,
+    instance_id: &str,
+    message_slot: i32,
+    concurrency: usize,
+) -> Result<()> {
+    let recipients = collect_recipients(api_config, &options).await?;
+    if recipients.is_empty() {
+        return Err(anyhow::anyhow!(
+            "No recipients matched; no invites were sent"
+        ));
+    }
+
+    println!("Sending {} invite(s) to {instance_id}...", recipients.len());
+
+    // Recipients are released one at a time, so sends start at least INVITE_PACING apart
+    // even while several are in flight. Results come back in recipient order so the
+    // summary is stable.
+    let results: Vec<(Recipient, Result<()>)> = stream::iter(recipients)
+        .then(|recipient| async move {
+            tokio::time::sleep(INVITE_PACING).await;
+            recipient
+        })
+        .map(|recipient| async move {
+            let result =
+                send_invite(api_config, &recipient.user_id, instance_id, message_slot).await;
+            (recipient, result)
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut failed = 0;
+    for (recipient, result) in &results {
+        match result {
+            Ok(()) => println!("✅ {} ({})", recipient.label, recipient.user_id),
+            Err(e) => {
+                failed += 1;
+                println!("❌ {} ({}): {e}", recipient.label, recipient.user_id);
+            }
+        }
+    }
+
+    let sent = results.len() - failed;
+    println!("Sent {sent} of {} invite(s)", results.len());
+
+    if failed > 0 {
+        return Err(anyhow::anyhow!(
+            "{} of {} invite(s) failed",
+            failed,
+            results.len()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Resolve all recipient sources into a de-duplicated list
+async fn collect_recipients(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    options: &RecipientOptions,
+) -> Result<Vec<Recipient>> {
+    let mut recipients = Vec::new();
+
+    // Resolve every explicit user up front so a typo does not leave a half-sent batch
+    for identifier in &options.users {
+        let user_id = crate::common::user_operations::resolve_user_identifier(
+            api_config,
+            identifier,
+            options.use_direct_id,
+        )
+        .await?;
+        recipients.push(Recipient {
+            user_id,
+            label: identifier.clone(),
+        });
+    }
+
+    if let Some(group_name) = &options.favorite_group {
+        let group =
+            favorites::find_favorite_group(api_config, FavoriteType::Friend, group_name).await?;
+        let user_ids =
+            favorites::fetch_favorite_ids(api_config, FavoriteType::Friend, Some(&group.name))
+                .await?;
+
+        // Favorites only carry IDs; use cached friend names for the summary
+        let friends = crate::common::friend_cache::cached_friends(api_config)
+            .await
+            .unwrap_or_default();
+        for user_id in user_ids {
+            let label = friends
+                .iter()
+                .find(|friend| friend.id == user_id)
+                .map(|friend| friend.display_name.clone())
+                .unwrap_or_else(|| user_id.clone());
+            recipients.push(Recipient { user_id, label });
+        }
+    }
+
+    if let Some(expression) = &options.online_friends_filter {
+        let filter = FriendFilter::parse(expression)?;
+        let online =
+            crate::commands::friends::fetcher::fetch_pages_parallel(api_config, Some(false), None)
+                .await?;
+
+        // Friends on the website or app only cannot follow an invite
+        recipients.extend(
+            online
+                .iter()
+                .filter(|friend| {
+                    !matches!(
+                        location::parse_location(&friend.location),
+                        Ok(Location::Offline)
+                    )
+                })
+                .filter(|friend| filter.matches(friend))
+                .map(|friend| Recipient {
+                    user_id: friend.id.clone(),
+                    label: friend.display_name.clone(),
+                }),
+        );
+    }
+
+    let mut seen = HashSet::new();
+    recipients.retain(|recipient| seen.insert(recipient.user_id.clone()));
+    Ok(recipients)
+}
+
+/// Send one invite, backing off and retrying when rate limited
+async fn send_invite(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    user_id: &str,
+    instance_id: &str,
+    message_slot: i32,
+) -> Result<()> {
+    let mut attempt = 0;
+    loop {
+        let invite_request = InviteRequest {
+            instance_id: instance_id.to_string(),
+            message_slot: Some(message_slot),
+        };
+
+        match apis::invite_api::invite_user(api_config, user_id, invite_request).await {
+            Ok(_) => return Ok(()),
+            Err(vrchatapi::apis::Error::ResponseError(response))
+                if response.status.as_u16() == 429 && attempt + 1 < MAX_ATTEMPTS =>
+            {
+                attempt += 1;
+                tokio::time::sleep(Duration::from_secs(2u64.pow(attempt))).await;
+            }
+            Err(vrchatapi::apis::Error::ResponseError(response)) => {
+                return Err(anyhow::anyhow!(
+                    "HTTP {} {}",
+                    response.status.as_u16(),
+                    response.status.canonical_reason().unwrap_or("Unknown")
+                ));
+            }
+            Err(e) => return Err(anyhow::anyhow!("{}", e)),
+        }
+    }
+}
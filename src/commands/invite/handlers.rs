@@ -1,6 +1,8 @@
+use super::report::{InviteAttempt, InviteOutcome, InviteRequestReport, ReportOutput};
+use crate::common::location::{self, InstanceLocation};
 use anyhow::Result;
 use vrchatapi::apis;
-use vrchatapi::models::InviteRequest;
+use vrchatapi::models::{InviteRequest, InviteResponse, NotificationType};
 
 /// Handle the invite send action
 pub async fn handle_invite_send_action(
@@ -15,16 +17,15 @@ pub async fn handle_invite_send_action(
         crate::common::user_operations::resolve_user_identifier(api_config, user, use_direct_id)
             .await?;
 
-    let slot = message_slot.unwrap_or(0);
-
     match (instance_id, request_invite) {
         (Some(instance), false) => {
             // Send invite to specific instance
-            send_invite_to_instance(api_config, &user_id, &instance, slot).await
+            send_invite_to_instance(api_config, &user_id, &instance, message_slot.unwrap_or(0))
+                .await
         }
         (None, true) => {
             // Request invite (only when --request flag is explicitly set)
-            request_invite_from_user(api_config, &user_id, slot).await
+            request_invite_from_user(api_config, &user_id, message_slot).await
         }
         (None, false) => {
             // No instance_id and no --request flag provided
@@ -85,14 +86,37 @@ async fn send_invite_to_instance(
     }
 }
 
-/// Request an invite from a user using direct HTTP request (library doesn't handle None properly)
+/// Request an invite from a user, printing the result
 async fn request_invite_from_user(
     api_config: &vrchatapi::apis::configuration::Configuration,
     user_id: &str,
-    _message_slot: i32,
+    message_slot: Option<i32>,
 ) -> Result<()> {
-    // Use empty JSON object as request body - this is what works based on our debugging
-    let request_payload = serde_json::json!({});
+    let attempt = request_invite_attempt(api_config, user_id, message_slot).await;
+    if attempt.success {
+        println!("📞 Requested an invite from {user_id}");
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "Failed to request invite: {}",
+            attempt.reason.unwrap_or_default()
+        ))
+    }
+}
+
+/// Request an invite from a user using direct HTTP request (library doesn't handle None properly)
+async fn request_invite_attempt(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    user_id: &str,
+    message_slot: Option<i32>,
+) -> InviteAttempt {
+    const METHOD: &str = "request_invite";
+
+    // Use empty JSON object as request body unless a request message slot was chosen
+    let request_payload = match message_slot {
+        Some(slot) => serde_json::json!({ "requestSlot": slot }),
+        None => serde_json::json!({}),
+    };
 
     // Get the base URL
     let base_url = &api_config.base_path;
@@ -110,180 +134,301 @@ async fn request_invite_from_user(
     request_builder = request_builder.json(&request_payload);
 
     // Send the request
-    let response = request_builder.send().await?;
+    let response = match request_builder.send().await {
+        Ok(response) => response,
+        Err(e) => return InviteAttempt::failed(METHOD, user_id, None, e.to_string()),
+    };
 
     let status = response.status();
-    let response_text = response.text().await?;
+    let response_text = response.text().await.unwrap_or_default();
 
-    if status.is_success() {
-        // Parse the response as JSON
-        match serde_json::from_str::<serde_json::Value>(&response_text) {
-            Ok(_) => {
-                println!("✅ Successfully requested invite using traditional method!");
-            }
-            Err(e) => {
-                eprintln!("Failed to parse response JSON: {e}");
-                eprintln!("Raw response: {response_text}");
-                return Err(anyhow::anyhow!("Failed to parse response: {}", e));
-            }
-        }
-    } else {
-        eprintln!("Request failed with status: {status}");
-        eprintln!("Response body: {response_text}");
-        return Err(anyhow::anyhow!("Request failed with status: {}", status));
+    if !status.is_success() {
+        return InviteAttempt::failed(
+            METHOD,
+            user_id,
+            Some(status.as_u16()),
+            format!("Request failed: {response_text}"),
+        );
+    }
+
+    match serde_json::from_str::<serde_json::Value>(&response_text) {
+        Ok(_) => InviteAttempt::succeeded(METHOD, user_id),
+        Err(e) => InviteAttempt::failed(
+            METHOD,
+            user_id,
+            Some(status.as_u16()),
+            format!("Failed to parse response: {e}"),
+        ),
+    }
+}
+
+/// Handle the invite respond action
+/// Without a response slot the requester is invited to our current location.
+/// Declining hides the request, after replying with the response slot if one is given.
+pub async fn handle_invite_respond_action(
+    auth_client: &crate::common::auth_client::AuthenticatedClient,
+    target: &str,
+    use_direct_id: bool,
+    message_slot: Option<i32>,
+    response_slot: Option<i32>,
+    decline: bool,
+) -> Result<()> {
+    let api_config = auth_client.api_config();
+    let request = find_invite_request(api_config, target, use_direct_id).await?;
+    let sender = request
+        .sender_username
+        .clone()
+        .unwrap_or_else(|| request.sender_user_id.clone());
+
+    if let Some(slot) = response_slot {
+        apis::invite_api::respond_invite(api_config, &request.id, InviteResponse::new(slot))
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to respond to invite request: {}", e))?;
+        println!("Replied to {sender}'s invite request with response message slot {slot}");
+    } else if !decline {
+        let location = current_location(auth_client).await?;
+        send_invite_to_instance(
+            api_config,
+            &request.sender_user_id,
+            &location.location(),
+            message_slot.unwrap_or(0),
+        )
+        .await?;
+    }
+
+    if decline {
+        // The API names hiding a notification "delete"; it calls PUT .../hide
+        apis::notifications_api::delete_notification(api_config, &request.id)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to decline invite request: {}", e))?;
+        println!("Declined {sender}'s invite request");
+        return Ok(());
+    }
+
+    // The request has been handled either way
+    if let Err(e) =
+        apis::notifications_api::mark_notification_as_read(api_config, &request.id).await
+    {
+        eprintln!("Warning: Failed to mark notification as seen: {e}");
     }
 
     Ok(())
 }
 
+/// Find an invite request notification by notification ID or by its sender
+async fn find_invite_request(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    target: &str,
+    use_direct_id: bool,
+) -> Result<vrchatapi::models::Notification> {
+    use crate::commands::notifications::fetcher;
+
+    if target.starts_with("not_") {
+        let notification = fetcher::fetch_notification(api_config, target).await?;
+        if notification.r#type != NotificationType::RequestInvite {
+            return Err(anyhow::anyhow!(
+                "Notification {} is a '{}' notification, not an invite request",
+                target,
+                notification.r#type
+            ));
+        }
+        return Ok(notification);
+    }
+
+    let user_id =
+        crate::common::user_operations::resolve_user_identifier(api_config, target, use_direct_id)
+            .await?;
+    let requests =
+        fetcher::fetch_notifications(api_config, &[NotificationType::RequestInvite], false, 100)
+            .await?;
+
+    // Notifications are returned newest first
+    requests
+        .into_iter()
+        .find(|notification| notification.sender_user_id == user_id)
+        .ok_or_else(|| anyhow::anyhow!("No invite request from {} found", target))
+}
+
+/// Our own current location, from the presence of the logged-in user
+/// Refuses with a clear error when we are offline, traveling or in a private world
+pub async fn current_location(
+    auth_client: &crate::common::auth_client::AuthenticatedClient,
+) -> Result<InstanceLocation> {
+    let current_user = auth_client
+        .current_user()
+        .ok_or_else(|| anyhow::anyhow!("Current user is not available"))?;
+
+    let presence = current_user.presence.as_ref();
+    let world = presence.and_then(|p| p.world.clone()).unwrap_or_default();
+    let instance = presence
+        .and_then(|p| p.instance.clone().flatten())
+        .unwrap_or_default();
+    let traveling_to = presence
+        .and_then(|p| p.traveling_to_world.clone())
+        .unwrap_or_default();
+
+    let raw_location = if !traveling_to.is_empty() && traveling_to != "offline" {
+        "traveling".to_string()
+    } else if world.starts_with("wrld_") && !instance.is_empty() {
+        format!("{world}:{instance}")
+    } else if !world.is_empty() {
+        world
+    } else {
+        // No presence data: ask the users API where we are
+        crate::common::user_operations::fetch_user_by_resolved_id(
+            auth_client.api_config(),
+            &current_user.id,
+        )
+        .await?
+        .location
+        .unwrap_or_default()
+    };
+
+    location::require_instance(&raw_location, "You are")
+}
+
 /// Handle invite request with automatic location detection
+/// Tries to self-invite into the user's instance and falls back to requesting an invite
 pub async fn handle_invite_request_action(
     api_config: &vrchatapi::apis::configuration::Configuration,
     user: &str,
     use_direct_id: bool,
     message_slot: Option<i32>,
     force_request: bool,
+    output: ReportOutput,
 ) -> Result<()> {
     let user_id =
         crate::common::user_operations::resolve_user_identifier(api_config, user, use_direct_id)
             .await?;
 
-    let slot = message_slot.unwrap_or(0);
+    let mut report = InviteRequestReport::new(&user_id);
 
     // Skip auto location detection if force_request is true
-    if force_request {
-        println!("🔄 Using traditional invite request (--force-request specified)");
-        return request_invite_from_user(api_config, &user_id, slot).await;
-    }
+    if !force_request {
+        if let Some(instance) = lookup_joinable_location(api_config, &user_id, &mut report).await {
+            report.location = Some(instance.location());
 
-    // Fetch user details to check location availability
-    match crate::common::user_operations::fetch_user_by_resolved_id(api_config, &user_id).await {
-        Ok(user_info) => {
-            // Check if user is online and location is available
-            if user_info.status != vrchatapi::models::UserStatus::Offline {
-                if let Some(location) = &user_info.location {
-                    if !location.is_empty() && location != "private" && location.contains(':') {
-                        // Parse location to get world_id and instance_id
-                        let parts: Vec<&str> = location.split(':').collect();
-                        if parts.len() >= 2 {
-                            let world_id = parts[0];
-                            let full_instance_part = parts[1];
-
-                            // Extract the base instance ID (before any ~ modifiers)
-                            let instance_id = if let Some(tilde_pos) = full_instance_part.find('~')
-                            {
-                                &full_instance_part[..tilde_pos]
-                            } else {
-                                full_instance_part
-                            };
-
-                            // Try to use invite_myself_to API with base instance ID only
-                            println!(
-                                "🎯 Detected user location, attempting to use automatic invite..."
-                            );
-                            match invite_myself_to_instance(api_config, world_id, instance_id).await
-                            {
-                                Ok(_) => {
-                                    return Ok(());
-                                }
-                                Err(_) => {
-                                    // Second try: Use the complete instance part
-                                    println!("⚠️ First attempt failed, trying with full instance identifier...");
-                                    match invite_myself_to_instance(
-                                        api_config,
-                                        world_id,
-                                        full_instance_part,
-                                    )
-                                    .await
-                                    {
-                                        Ok(_) => {
-                                            return Ok(());
-                                        }
-                                        Err(_) => {
-                                            println!("⚠️ Automatic invite failed, falling back to traditional invite request...");
-                                        }
-                                    }
-                                }
-                            }
-                        } else {
-                            // Invalid location format
-                        }
-                    }
+            // First try the base instance ID only, then the complete instance part
+            let mut targets = vec![instance.name.as_str()];
+            if instance.instance_id != instance.name {
+                targets.push(instance.instance_id.as_str());
+            }
+
+            for target in targets {
+                let attempt = invite_myself_attempt(api_config, &instance.world_id, target).await;
+                if report.record(attempt) {
+                    report.outcome = InviteOutcome::Joined;
+                    return report.print(output);
                 }
             }
         }
-        Err(_) => {
-            // Fall back to traditional invite request
-        }
     }
 
     // Fallback to traditional invite request
-    println!("📞 Using traditional invite request method...");
-    request_invite_from_user(api_config, &user_id, slot).await
+    if report.record(request_invite_attempt(api_config, &user_id, message_slot).await) {
+        report.outcome = InviteOutcome::Requested;
+    }
+
+    report.print(output)?;
+
+    match report.outcome {
+        InviteOutcome::Failed => {
+            let reason = report
+                .attempts
+                .last()
+                .and_then(|attempt| attempt.reason.clone())
+                .unwrap_or_default();
+            Err(anyhow::anyhow!(
+                "Could not join or request an invite from {}: {}",
+                user,
+                reason
+            ))
+        }
+        _ => Ok(()),
+    }
 }
 
-/// Invite myself to a specific instance using the VRChat API
-async fn invite_myself_to_instance(
+/// Look up the user's location, recording why it cannot be joined when it can't
+async fn lookup_joinable_location(
     api_config: &vrchatapi::apis::configuration::Configuration,
-    world_id: &str,
-    instance_id: &str,
-) -> Result<()> {
-    match apis::invite_api::invite_myself_to(api_config, world_id, instance_id).await {
-        Ok(_) => {
-            println!("✅ Successfully invited yourself using automatic invite!");
-            println!("📍 Target: {world_id}:{instance_id}");
-            Ok(())
+    user_id: &str,
+    report: &mut InviteRequestReport,
+) -> Option<InstanceLocation> {
+    const METHOD: &str = "lookup_location";
+
+    let user_info = match apis::users_api::get_user(api_config, user_id).await {
+        Ok(user_info) => user_info,
+        Err(e) => {
+            let status = match &e {
+                vrchatapi::apis::Error::ResponseError(response) => Some(response.status.as_u16()),
+                _ => None,
+            };
+            report.record(InviteAttempt::failed(
+                METHOD,
+                user_id,
+                status,
+                e.to_string(),
+            ));
+            return None;
+        }
+    };
+
+    let raw_location = if user_info.status == vrchatapi::models::UserStatus::Offline {
+        "offline".to_string()
+    } else {
+        user_info.location.unwrap_or_default()
+    };
+
+    match location::require_instance(&raw_location, "User is") {
+        Ok(instance) => {
+            report.record(InviteAttempt::succeeded(METHOD, &instance.location()));
+            Some(instance)
         }
         Err(e) => {
-            // Enhanced error reporting
-            match &e {
-                vrchatapi::apis::Error::ResponseError(response_content) => {
-                    let status = response_content.status;
-                    let content = &response_content.content;
-
-                    match status.as_u16() {
-                        400 => {
-                            Err(anyhow::anyhow!(
-                                "Failed to invite myself to instance: error in response: status code 400 Bad Request\n\
-                                Possible causes:\n\
-                                - Instance is friends-only and you're not friends with the instance creator\n\
-                                - Instance is invite-only\n\
-                                - Instance has reached maximum capacity\n\
-                                - Invalid instance format or world doesn't exist\n\
-                                Response body: {}", content
-                            ))
-                        }
-                        401 => {
-                            Err(anyhow::anyhow!(
-                                "Failed to invite myself to instance: Unauthorized (401)\n\
-                                Please check your authentication credentials."
-                            ))
-                        }
-                        403 => {
-                            Err(anyhow::anyhow!(
-                                "Failed to invite myself to instance: Forbidden (403)\n\
-                                You don't have permission to join this instance."
-                            ))
-                        }
-                        404 => {
-                            Err(anyhow::anyhow!(
-                                "Failed to invite myself to instance: Not Found (404)\n\
-                                The world or instance doesn't exist."
-                            ))
-                        }
-                        _ => {
-                            Err(anyhow::anyhow!(
-                                "Failed to invite myself to instance: HTTP {} - {}\n\
-                                Response body: {}", status, status.canonical_reason().unwrap_or("Unknown"), content
-                            ))
-                        }
-                    }
-                }
-                _ => Err(anyhow::anyhow!(
-                    "Failed to invite myself to instance: {}",
-                    e
-                )),
-            }
+            report.record(InviteAttempt::failed(METHOD, user_id, None, e.to_string()));
+            None
         }
     }
 }
+
+/// Invite myself to a specific instance using the VRChat API
+async fn invite_myself_attempt(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    world_id: &str,
+    instance_id: &str,
+) -> InviteAttempt {
+    const METHOD: &str = "invite_myself_to";
+    let target = format!("{world_id}:{instance_id}");
+
+    let e = match apis::invite_api::invite_myself_to(api_config, world_id, instance_id).await {
+        Ok(_) => return InviteAttempt::succeeded(METHOD, &target),
+        Err(e) => e,
+    };
+
+    let vrchatapi::apis::Error::ResponseError(response_content) = &e else {
+        return InviteAttempt::failed(METHOD, &target, None, e.to_string());
+    };
+
+    let status = response_content.status;
+    let reason = match status.as_u16() {
+        400 => format!(
+            "Bad Request. Possible causes:\n\
+            - Instance is friends-only and you're not friends with the instance creator\n\
+            - Instance is invite-only\n\
+            - Instance has reached maximum capacity\n\
+            - Invalid instance format or world doesn't exist\n\
+            Response body: {}",
+            response_content.content
+        ),
+        401 => "Unauthorized. Please check your authentication credentials.".to_string(),
+        403 => "Forbidden. You don't have permission to join this instance.".to_string(),
+        404 => "Not Found. The world or instance doesn't exist.".to_string(),
+        _ => format!(
+            "{} - Response body: {}",
+            status.canonical_reason().unwrap_or("Unknown"),
+            response_content.content
+        ),
+    };
+
+    InviteAttempt::failed(METHOD, &target, Some(status.as_u16()), reason)
+}
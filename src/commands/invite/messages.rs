@@ -0,0 +1,110 @@
+use super::table_adapter::InviteMessageTableItem;
+use crate::common::{command_utils::display_results, display_options::DisplayOptions};
+use anyhow::Result;
+use vrchatapi::apis;
+use vrchatapi::models::{InviteMessageType, UpdateInviteMessageRequest};
+
+/// Message collection names accepted by `--type`
+const MESSAGE_TYPES: &[&str] = &["message", "response", "request", "requestResponse"];
+
+/// How long a slot is locked after it has been edited
+const EDIT_COOLDOWN_MINUTES: i32 = 60;
+
+/// Parse a message collection name as used by the API (e.g. `requestResponse`)
+fn parse_message_type(name: &str) -> Result<InviteMessageType> {
+    serde_json::from_value(serde_json::Value::String(name.to_string())).map_err(|_| {
+        anyhow::anyhow!(
+            "Unknown message type '{}'. Valid types: {}",
+            name,
+            MESSAGE_TYPES.join(", ")
+        )
+    })
+}
+
+/// Fetch the invite messages of one collection
+async fn fetch_invite_messages(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    user_id: &str,
+    message_type: InviteMessageType,
+) -> Result<Vec<vrchatapi::models::InviteMessage>> {
+    apis::invite_api::get_invite_messages(api_config, user_id, message_type)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch {} messages: {}", message_type, e))
+}
+
+/// Handle the messages list action
+pub async fn handle_list_action(
+    auth_client: &crate::common::auth_client::AuthenticatedClient,
+    message_type: Option<&str>,
+    display_options: DisplayOptions,
+) -> Result<()> {
+    let api_config = auth_client.api_config();
+    let user_id = current_user_id(auth_client)?;
+
+    let types = match message_type {
+        Some(name) => vec![parse_message_type(name)?],
+        None => MESSAGE_TYPES
+            .iter()
+            .map(|name| parse_message_type(name))
+            .collect::<Result<Vec<_>>>()?,
+    };
+
+    let mut table_items = Vec::new();
+    for message_type in types {
+        let mut messages = fetch_invite_messages(api_config, user_id, message_type).await?;
+        messages.sort_by_key(|message| message.slot);
+        table_items.extend(messages.into_iter().map(InviteMessageTableItem::from));
+    }
+
+    display_results(&table_items, &display_options, "No invite messages found.")
+}
+
+/// Handle the messages set action
+pub async fn handle_set_action(
+    auth_client: &crate::common::auth_client::AuthenticatedClient,
+    message_type: &str,
+    slot: i32,
+    text: &str,
+) -> Result<()> {
+    let api_config = auth_client.api_config();
+    let user_id = current_user_id(auth_client)?;
+    let message_type = parse_message_type(message_type)?;
+
+    // Check the cooldown first to give a clearer error than the API's 429
+    let messages = fetch_invite_messages(api_config, user_id, message_type).await?;
+    let current = messages
+        .iter()
+        .find(|message| message.slot == slot)
+        .ok_or_else(|| anyhow::anyhow!("No {} message in slot {}", message_type, slot))?;
+
+    if current.remaining_cooldown_minutes > 0 {
+        return Err(anyhow::anyhow!(
+            "{} message slot {} was edited recently and can be changed again in {} minute(s)",
+            message_type,
+            slot,
+            current.remaining_cooldown_minutes
+        ));
+    }
+
+    apis::invite_api::update_invite_message(
+        api_config,
+        user_id,
+        message_type,
+        slot,
+        Some(UpdateInviteMessageRequest::new(text.to_string())),
+    )
+    .await
+    .map_err(|e| anyhow::anyhow!("Failed to update {} message: {}", message_type, e))?;
+
+    println!("Updated {message_type} message slot {slot}: {text}");
+    eprintln!("Warning: This slot cannot be edited again for {EDIT_COOLDOWN_MINUTES} minutes");
+
+    Ok(())
+}
+
+fn current_user_id(auth_client: &crate::common::auth_client::AuthenticatedClient) -> Result<&str> {
+    auth_client
+        .current_user()
+        .map(|user| user.id.as_str())
+        .ok_or_else(|| anyhow::anyhow!("Current user is not available"))
+}
@@ -1,8 +1,14 @@
-mod handlers;
+mod batch;
+pub(crate) mod handlers;
+mod messages;
+mod random;
+pub(crate) mod report;
+mod table_adapter;
 
 use crate::common::auth_client::AuthenticatedClient;
+use crate::common::display_options::DisplayOptions;
 use anyhow::Result;
-use vrcli::InviteAction;
+use vrcli::{InviteAction, InviteMessagesAction};
 
 pub async fn handle_invite_command(action: InviteAction) -> Result<()> {
     let auth_client = AuthenticatedClient::new().await?;
@@ -12,13 +18,55 @@ pub async fn handle_invite_command(action: InviteAction) -> Result<()> {
         InviteAction::Send {
             user,
             instance_id,
+            here,
             id,
             message_slot,
+            to,
+            to_favorites,
+            to_online_friends,
+            concurrency,
         } => {
+            let is_batch = !to.is_empty() || to_favorites.is_some() || to_online_friends.is_some();
+
+            // With --to options a single positional argument is the instance
+            let (user, instance_id) = match (user, instance_id) {
+                (Some(instance), None) if is_batch && !here => (None, Some(instance)),
+                other => other,
+            };
+
+            let instance_id = if here {
+                let location = handlers::current_location(&auth_client).await?;
+                println!("Inviting to your current instance: {location}");
+                Some(location.location())
+            } else {
+                instance_id
+            };
+
+            if is_batch {
+                let instance_id = instance_id.ok_or_else(|| {
+                    anyhow::anyhow!("Specify an instance ID or --here to send invites")
+                })?;
+                let recipients = batch::RecipientOptions {
+                    users: user.into_iter().chain(to).collect(),
+                    use_direct_id: id,
+                    favorite_group: to_favorites,
+                    online_friends_filter: to_online_friends,
+                };
+                return batch::handle_batch_invite_action(
+                    api_config,
+                    recipients,
+                    &instance_id,
+                    message_slot.unwrap_or(0),
+                    concurrency,
+                )
+                .await;
+            }
+
+            let user = user.ok_or_else(|| anyhow::anyhow!("Specify a user to invite"))?;
             handlers::handle_invite_send_action(
                 api_config,
                 &user,
-                Some(instance_id),
+                instance_id,
                 id,
                 false,
                 message_slot,
@@ -27,18 +75,79 @@ pub async fn handle_invite_command(action: InviteAction) -> Result<()> {
         }
         InviteAction::Request {
             user,
+            random,
+            filter,
+            prefer_join_me,
+            prefer_favorites,
             id,
             message_slot,
             force_request,
+            verbose,
+            json,
         } => {
+            let (user, id) = if random {
+                let options = random::RandomPickOptions {
+                    filter,
+                    prefer_join_me,
+                    prefer_favorites,
+                };
+                let friend = random::pick_random_friend(api_config, &options).await?;
+                // Keep stdout clean for the JSON report
+                let picked = format!("🎲 Picked {} ({})", friend.display_name, friend.id);
+                if json {
+                    eprintln!("{picked}");
+                } else {
+                    println!("{picked}");
+                }
+                (friend.id, true)
+            } else {
+                let user = user.ok_or_else(|| anyhow::anyhow!("Specify a user or --random"))?;
+                (user, id)
+            };
+
             handlers::handle_invite_request_action(
                 api_config,
                 &user,
                 id,
                 message_slot,
                 force_request,
+                report::ReportOutput { verbose, json },
+            )
+            .await
+        }
+        InviteAction::Respond {
+            target,
+            id,
+            message_slot,
+            slot,
+            decline,
+        } => {
+            handlers::handle_invite_respond_action(
+                &auth_client,
+                &target,
+                id,
+                message_slot,
+                slot,
+                decline,
             )
             .await
         }
+        InviteAction::Messages { action } => match action {
+            InviteMessagesAction::List { r#type, json } => {
+                let display_options = DisplayOptions::from_flags(
+                    false, // long_format
+                    false, // show_id
+                    true,  // show_status (slot)
+                    false, // show_platform
+                    true,  // show_location (message)
+                    true,  // show_activity (editable)
+                    json,  // json
+                );
+                messages::handle_list_action(&auth_client, r#type.as_deref(), display_options).await
+            }
+            InviteMessagesAction::Set { slot, text, r#type } => {
+                messages::handle_set_action(&auth_client, &r#type, slot, &text).await
+            }
+        },
     }
 }
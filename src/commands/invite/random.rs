@@ -0,0 +1,80 @@
+use crate::common::favorites;
+use crate::common::friend_filter::FriendFilter;
+use crate::common::location::{self, Location};
+use anyhow::Result;
+use rand::distr::weighted::WeightedIndex;
+use rand::distr::Distribution;
+use std::collections::HashSet;
+use vrchatapi::models::{FavoriteType, LimitedUserFriend, UserStatus};
+
+/// How much more likely a preferred friend is to be picked
+const PREFERRED_WEIGHT: f64 = 4.0;
+
+/// Options for picking a random friend
+#[derive(Debug, Clone, Default)]
+pub struct RandomPickOptions {
+    pub filter: Option<String>,
+    pub prefer_join_me: bool,
+    pub prefer_favorites: bool,
+}
+
+/// Pick a random online friend whose instance can be joined
+pub async fn pick_random_friend(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    options: &RandomPickOptions,
+) -> Result<LimitedUserFriend> {
+    let filter = FriendFilter::parse(options.filter.as_deref().unwrap_or(""))?;
+
+    let online =
+        crate::commands::friends::fetcher::fetch_pages_parallel(api_config, Some(false), None)
+            .await?;
+
+    // Offline, private, traveling and invite-only locations cannot be joined
+    let candidates: Vec<LimitedUserFriend> = online
+        .into_iter()
+        .filter(|friend| {
+            matches!(
+                location::parse_location(&friend.location),
+                Ok(Location::Instance(instance)) if instance.access.is_joinable()
+            )
+        })
+        .filter(|friend| !friend.display_name.trim().is_empty())
+        .filter(|friend| filter.matches(friend))
+        .collect();
+
+    if candidates.is_empty() {
+        return Err(anyhow::anyhow!(
+            "No online friends found in joinable instances"
+        ));
+    }
+
+    let favorite_ids: HashSet<String> = if options.prefer_favorites {
+        favorites::fetch_favorite_ids(api_config, FavoriteType::Friend, None)
+            .await?
+            .into_iter()
+            .collect()
+    } else {
+        HashSet::new()
+    };
+
+    let weights: Vec<f64> = candidates
+        .iter()
+        .map(|friend| {
+            let mut weight = 1.0;
+            if options.prefer_join_me && friend.status == UserStatus::JoinMe {
+                weight *= PREFERRED_WEIGHT;
+            }
+            if favorite_ids.contains(&friend.id) {
+                weight *= PREFERRED_WEIGHT;
+            }
+            weight
+        })
+        .collect();
+
+    let distribution = WeightedIndex::new(&weights)
+        .map_err(|e| anyhow::anyhow!("Failed to pick a friend: {}", e))?;
+    let index = distribution.sample(&mut rand::rng());
+
+    let mut candidates = candidates;
+    Ok(candidates.swap_remove(index))
+}
@@ -0,0 +1,129 @@
+use serde::Serialize;
+
+/// How the invite request report is shown
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReportOutput {
+    /// Print every attempted step
+    pub verbose: bool,
+    /// Print the report as JSON instead of text
+    pub json: bool,
+}
+
+/// One step of the invite request chain
+#[derive(Debug, Clone, Serialize)]
+pub struct InviteAttempt {
+    /// `lookup_location`, `invite_myself_to` or `request_invite`
+    pub method: &'static str,
+    /// User ID or location the step was aimed at
+    pub target: String,
+    pub success: bool,
+    pub http_status: Option<u16>,
+    pub reason: Option<String>,
+}
+
+impl InviteAttempt {
+    pub fn succeeded(method: &'static str, target: &str) -> Self {
+        Self {
+            method,
+            target: target.to_string(),
+            success: true,
+            http_status: None,
+            reason: None,
+        }
+    }
+
+    pub fn failed(
+        method: &'static str,
+        target: &str,
+        http_status: Option<u16>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            method,
+            target: target.to_string(),
+            success: false,
+            http_status,
+            reason: Some(reason.into()),
+        }
+    }
+}
+
+/// What the invite request chain achieved
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InviteOutcome {
+    /// A self-invite to the friend's instance was sent; the game can join directly
+    Joined,
+    /// Only an invite request was sent; the friend still has to invite us
+    Requested,
+    Failed,
+}
+
+/// Structured record of `invite request` / `friends join`
+#[derive(Debug, Clone, Serialize)]
+pub struct InviteRequestReport {
+    pub user_id: String,
+    pub location: Option<String>,
+    pub outcome: InviteOutcome,
+    pub attempts: Vec<InviteAttempt>,
+}
+
+impl InviteRequestReport {
+    pub fn new(user_id: &str) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            location: None,
+            outcome: InviteOutcome::Failed,
+            attempts: Vec::new(),
+        }
+    }
+
+    /// Record a step, returning whether it succeeded
+    pub fn record(&mut self, attempt: InviteAttempt) -> bool {
+        let success = attempt.success;
+        self.attempts.push(attempt);
+        success
+    }
+
+    /// Print the report according to the output options
+    pub fn print(&self, output: ReportOutput) -> anyhow::Result<()> {
+        if output.json {
+            println!("{}", serde_json::to_string_pretty(self)?);
+            return Ok(());
+        }
+
+        if output.verbose {
+            for (index, attempt) in self.attempts.iter().enumerate() {
+                let result = if attempt.success { "ok" } else { "failed" };
+                let status = attempt
+                    .http_status
+                    .map(|status| format!(" (HTTP {status})"))
+                    .unwrap_or_default();
+                println!(
+                    "{}. {} {}: {result}{status}",
+                    index + 1,
+                    attempt.method,
+                    attempt.target
+                );
+                if let Some(reason) = &attempt.reason {
+                    for line in reason.lines() {
+                        println!("   {line}");
+                    }
+                }
+            }
+        }
+
+        match self.outcome {
+            InviteOutcome::Joined => println!(
+                "✅ Sent yourself an invite to {}",
+                self.location.as_deref().unwrap_or(&self.user_id)
+            ),
+            InviteOutcome::Requested => {
+                println!("📞 Requested an invite from {}", self.user_id)
+            }
+            InviteOutcome::Failed => {}
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use crate::common::output_options::OutputOptions;
+use crate::common::table::{TableColumnNames, TableDisplayable};
+use serde_json::{Map, Value};
+
+/// Adapter for converting invite message data to table format
+pub struct InviteMessageTableItem {
+    pub id: String,
+    pub message_type: String,
+    pub slot: i32,
+    pub message: String,
+    pub remaining_cooldown_minutes: i32,
+    pub cooldown: String,
+    pub updated_at: String,
+}
+
+impl TableDisplayable for InviteMessageTableItem {
+    fn display_name(&self) -> &str {
+        &self.message_type
+    }
+
+    fn id(&self) -> Option<&str> {
+        Some(&self.id)
+    }
+
+    fn status(&self) -> Option<String> {
+        // Reuse status field for the slot number
+        Some(self.slot.to_string())
+    }
+
+    fn location(&self) -> Option<&str> {
+        Some(&self.message)
+    }
+
+    fn activity(&self) -> Option<&str> {
+        Some(&self.cooldown)
+    }
+
+    fn column_names(&self) -> TableColumnNames {
+        TableColumnNames {
+            name: "Type",
+            id: "ID",
+            status: "Slot",
+            platform: "Platform",
+            location: "Message",
+            activity: "Editable",
+        }
+    }
+
+    fn to_json_object(&self, _options: &OutputOptions) -> Value {
+        let mut map = Map::new();
+
+        map.insert("id".to_string(), Value::String(self.id.clone()));
+        map.insert("type".to_string(), Value::String(self.message_type.clone()));
+        map.insert("slot".to_string(), Value::Number(self.slot.into()));
+        map.insert("message".to_string(), Value::String(self.message.clone()));
+        map.insert(
+            "remaining_cooldown_minutes".to_string(),
+            Value::Number(self.remaining_cooldown_minutes.into()),
+        );
+        map.insert(
+            "updated_at".to_string(),
+            Value::String(self.updated_at.clone()),
+        );
+
+        Value::Object(map)
+    }
+}
+
+/// Convert InviteMessage model to InviteMessageTableItem
+impl From<vrchatapi::models::InviteMessage> for InviteMessageTableItem {
+    fn from(message: vrchatapi::models::InviteMessage) -> Self {
+        let cooldown = if message.remaining_cooldown_minutes > 0 {
+            format!("in {} min", message.remaining_cooldown_minutes)
+        } else {
+            "Yes".to_string()
+        };
+
+        InviteMessageTableItem {
+            id: message.id,
+            message_type: message.message_type.to_string(),
+            slot: message.slot,
+            message: message.message,
+            remaining_cooldown_minutes: message.remaining_cooldown_minutes,
+            cooldown,
+            updated_at: message.updated_at,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use anyhow::Result;
+use vrchatapi::apis::notifications_api;
+use vrchatapi::models::NotificationType;
+
+/// Notification type names accepted by `--type`
+pub const NOTIFICATION_TYPES: &[&str] = &[
+    "friendRequest",
+    "invite",
+    "inviteResponse",
+    "message",
+    "requestInvite",
+    "requestInviteResponse",
+    "votetokick",
+];
+
+/// Parse notification type names as used by the API (e.g. `requestInvite`)
+pub fn parse_notification_types(types: &[String]) -> Result<Vec<NotificationType>> {
+    types
+        .iter()
+        .map(|name| {
+            serde_json::from_value(serde_json::Value::String(name.clone())).map_err(|_| {
+                anyhow::anyhow!(
+                    "Unknown notification type '{}'. Valid types: {}",
+                    name,
+                    NOTIFICATION_TYPES.join(", ")
+                )
+            })
+        })
+        .collect()
+}
+
+/// Fetch notifications, filtered by type and seen state
+pub async fn fetch_notifications(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    types: &[NotificationType],
+    unseen_only: bool,
+    limit: i32,
+) -> Result<Vec<vrchatapi::models::Notification>> {
+    // The API filters by a single type only; several types are filtered locally
+    let type_param = match types {
+        [single] => single.to_string(),
+        _ => "all".to_string(),
+    };
+
+    let notifications = notifications_api::get_notifications(
+        api_config,
+        Some(&type_param),
+        None, // sent
+        None, // hidden
+        None, // after
+        Some(limit),
+        None, // offset
+    )
+    .await
+    .map_err(|e| anyhow::anyhow!("Failed to fetch notifications: {}", e))?;
+
+    Ok(notifications
+        .into_iter()
+        .filter(|n| types.is_empty() || types.contains(&n.r#type))
+        .filter(|n| !unseen_only || n.seen != Some(true))
+        .collect())
+}
+
+/// Fetch a single notification by ID
+pub async fn fetch_notification(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    notification_id: &str,
+) -> Result<vrchatapi::models::Notification> {
+    notifications_api::get_notification(api_config, notification_id)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch notification '{}': {}", notification_id, e))
+}
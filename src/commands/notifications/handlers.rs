@@ -0,0 +1,139 @@
+use super::{fetcher, table_adapter::NotificationTableItem};
+use crate::common::{command_utils::display_results, display_options::DisplayOptions};
+use anyhow::Result;
+use vrchatapi::apis;
+use vrchatapi::models::{InviteResponse, NotificationType};
+
+/// Configuration for notification list options
+#[derive(Debug, Clone)]
+pub struct NotificationListOptions {
+    pub types: Vec<String>,
+    pub unseen: bool,
+    pub limit: i32,
+}
+
+/// Handle the List action
+pub async fn handle_list_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    options: NotificationListOptions,
+    display_options: DisplayOptions,
+) -> Result<()> {
+    let types = fetcher::parse_notification_types(&options.types)?;
+    let notifications =
+        fetcher::fetch_notifications(api_config, &types, options.unseen, options.limit).await?;
+
+    let table_items: Vec<NotificationTableItem> = notifications
+        .into_iter()
+        .map(NotificationTableItem::from)
+        .collect();
+
+    display_results(&table_items, &display_options, "No notifications found.")
+}
+
+/// Handle the Accept action
+pub async fn handle_accept_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    notification_id: &str,
+) -> Result<()> {
+    let notification = fetcher::fetch_notification(api_config, notification_id).await?;
+    let sender = notification
+        .sender_username
+        .clone()
+        .unwrap_or_else(|| notification.sender_user_id.clone());
+
+    match notification.r#type {
+        NotificationType::FriendRequest => {
+            apis::notifications_api::accept_friend_request(api_config, notification_id)
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to accept friend request: {}", e))?;
+            println!("Accepted friend request from {sender}");
+        }
+        NotificationType::Invite => {
+            let details: serde_json::Value =
+                serde_json::from_str(&notification.details).unwrap_or_default();
+            let location = details
+                .get("worldId")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| anyhow::anyhow!("Invite from {} has no location", sender))?;
+            let (world_id, instance_id) = location
+                .split_once(':')
+                .ok_or_else(|| anyhow::anyhow!("Invalid invite location '{}'", location))?;
+
+            apis::invite_api::invite_myself_to(api_config, world_id, instance_id)
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to accept invite: {}", e))?;
+
+            let world_name = details
+                .get("worldName")
+                .and_then(|v| v.as_str())
+                .unwrap_or(world_id);
+            println!("Accepted invite from {sender} to {world_name}");
+            println!("A self-invite to {location} was sent to your game client.");
+
+            if let Err(e) =
+                apis::notifications_api::mark_notification_as_read(api_config, notification_id)
+                    .await
+            {
+                eprintln!("Warning: Failed to mark notification as seen: {e}");
+            }
+        }
+        NotificationType::RequestInvite => {
+            return Err(anyhow::anyhow!(
+                "Invite requests are answered with 'vrcli invite respond {}'",
+                notification_id
+            ));
+        }
+        other => {
+            return Err(anyhow::anyhow!(
+                "Notifications of type '{}' cannot be accepted. Use 'vrcli notifications respond' to reply instead.",
+                other
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle the Respond action
+pub async fn handle_respond_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    notification_id: &str,
+    slot: i32,
+) -> Result<()> {
+    let notification =
+        apis::invite_api::respond_invite(api_config, notification_id, InviteResponse::new(slot))
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to respond to notification: {}", e))?;
+
+    println!("Responded to {notification_id} with message slot {slot}");
+    if !notification.message.is_empty() {
+        println!("Message: {}", notification.message);
+    }
+
+    Ok(())
+}
+
+/// Handle the Hide action
+pub async fn handle_hide_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    notification_id: &str,
+) -> Result<()> {
+    apis::notifications_api::delete_notification(api_config, notification_id)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to hide notification: {}", e))?;
+
+    println!("Notification {notification_id} hidden");
+    Ok(())
+}
+
+/// Handle the Clear action
+pub async fn handle_clear_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+) -> Result<()> {
+    apis::notifications_api::clear_notifications(api_config)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to clear notifications: {}", e))?;
+
+    println!("All notifications cleared");
+    Ok(())
+}
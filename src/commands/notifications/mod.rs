@@ -0,0 +1,51 @@
+pub(crate) mod fetcher;
+mod handlers;
+mod table_adapter;
+
+use crate::common::auth_client::AuthenticatedClient;
+use crate::common::display_options::DisplayOptions;
+use anyhow::Result;
+use handlers::NotificationListOptions;
+use vrcli::NotificationsAction;
+
+pub async fn handle_notifications_command(action: NotificationsAction) -> Result<()> {
+    let auth_client = AuthenticatedClient::new().await?;
+    let api_config = auth_client.api_config();
+
+    match action {
+        NotificationsAction::List {
+            r#type,
+            unseen,
+            limit,
+            json,
+            long,
+        } => {
+            let options = NotificationListOptions {
+                types: r#type,
+                unseen,
+                limit,
+            };
+            let display_options = DisplayOptions::from_flags(
+                long, // long_format
+                true, // show_id - needed to accept, respond or hide
+                true, // show_status (type)
+                long, // show_platform (seen) - show when long format is enabled
+                true, // show_location (message)
+                true, // show_activity (received)
+                json, // json
+            );
+            handlers::handle_list_action(api_config, options, display_options).await
+        }
+        NotificationsAction::Accept { notification_id } => {
+            handlers::handle_accept_action(api_config, &notification_id).await
+        }
+        NotificationsAction::Respond {
+            notification_id,
+            slot,
+        } => handlers::handle_respond_action(api_config, &notification_id, slot).await,
+        NotificationsAction::Hide { notification_id } => {
+            handlers::handle_hide_action(api_config, &notification_id).await
+        }
+        NotificationsAction::Clear => handlers::handle_clear_action(api_config).await,
+    }
+}
@@ -0,0 +1,118 @@
+use crate::common::output_options::OutputOptions;
+use crate::common::table::{TableColumnNames, TableDisplayable};
+use serde_json::{Map, Value};
+
+/// Adapter for converting notification data to table format
+pub struct NotificationTableItem {
+    pub id: String,
+    pub notification_type: String,
+    pub sender_id: String,
+    pub sender_name: String,
+    pub summary: String,
+    pub message: String,
+    pub details: Value,
+    pub seen: bool,
+    pub created_at: String,
+    pub received: String,
+}
+
+impl TableDisplayable for NotificationTableItem {
+    fn display_name(&self) -> &str {
+        &self.sender_name
+    }
+
+    fn id(&self) -> Option<&str> {
+        Some(&self.id)
+    }
+
+    fn status(&self) -> Option<String> {
+        // Reuse status field for the notification type
+        Some(self.notification_type.clone())
+    }
+
+    fn formatted_platform(&self) -> Option<String> {
+        // Reuse platform field for the seen flag
+        Some(if self.seen { "Yes" } else { "No" }.to_string())
+    }
+
+    fn location(&self) -> Option<&str> {
+        Some(&self.summary)
+    }
+
+    fn activity(&self) -> Option<&str> {
+        Some(&self.received)
+    }
+
+    fn column_names(&self) -> TableColumnNames {
+        TableColumnNames {
+            name: "From",
+            id: "ID",
+            status: "Type",
+            platform: "Seen",
+            location: "Message",
+            activity: "Received",
+        }
+    }
+
+    fn to_json_object(&self, options: &OutputOptions) -> Value {
+        let mut map = Map::new();
+
+        map.insert("id".to_string(), Value::String(self.id.clone()));
+        map.insert(
+            "type".to_string(),
+            Value::String(self.notification_type.clone()),
+        );
+        map.insert(
+            "sender_id".to_string(),
+            Value::String(self.sender_id.clone()),
+        );
+        map.insert(
+            "sender_name".to_string(),
+            Value::String(self.sender_name.clone()),
+        );
+        map.insert("message".to_string(), Value::String(self.message.clone()));
+        map.insert("seen".to_string(), Value::Bool(self.seen));
+        map.insert(
+            "created_at".to_string(),
+            Value::String(self.created_at.clone()),
+        );
+
+        if options.json || options.long_format {
+            map.insert("details".to_string(), self.details.clone());
+        }
+
+        Value::Object(map)
+    }
+}
+
+/// Convert Notification model to NotificationTableItem
+impl From<vrchatapi::models::Notification> for NotificationTableItem {
+    fn from(notification: vrchatapi::models::Notification) -> Self {
+        // Details are a JSON encoded string; invites carry the world name there
+        let details: Value =
+            serde_json::from_str(&notification.details).unwrap_or(Value::Object(Map::new()));
+
+        let summary = if !notification.message.is_empty() {
+            notification.message.clone()
+        } else if let Some(world_name) = details.get("worldName").and_then(|v| v.as_str()) {
+            world_name.to_string()
+        } else {
+            String::new()
+        };
+
+        NotificationTableItem {
+            sender_name: notification
+                .sender_username
+                .unwrap_or_else(|| notification.sender_user_id.clone()),
+            id: notification.id,
+            notification_type: notification.r#type.to_string(),
+            sender_id: notification.sender_user_id,
+            summary,
+            message: notification.message,
+            details,
+            seen: notification.seen.unwrap_or(false),
+            received: crate::common::utils::format_api_timestamp(&notification.created_at),
+            created_at: notification.created_at,
+        }
+    }
+}
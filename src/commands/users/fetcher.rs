@@ -20,18 +20,18 @@ pub async fn fetch_user_by_name(
     Ok(user)
 }
 
-/// Search users by query
+/// Search users by query, returning one page of results
 pub async fn search_users(
     api_config: &vrchatapi::apis::configuration::Configuration,
     query: &str,
-    limit: i32,
+    developer_type: Option<&str>,
     offset: i32,
-    developer_type: Option<String>,
+    limit: i32,
 ) -> Result<Vec<vrchatapi::models::LimitedUserSearch>> {
     let users = users_api::search_users(
         api_config,
         Some(query),
-        developer_type.as_deref(),
+        developer_type,
         Some(limit),
         Some(offset),
     )
@@ -39,11 +39,13 @@ pub async fn search_users(
     Ok(users)
 }
 
-/// Fetch user notes
+/// Fetch one page of user notes
 pub async fn fetch_user_notes(
     api_config: &vrchatapi::apis::configuration::Configuration,
+    offset: i32,
+    limit: i32,
 ) -> Result<Vec<vrchatapi::models::UserNote>> {
-    let notes = users_api::get_user_notes(api_config, None, None).await?;
+    let notes = users_api::get_user_notes(api_config, Some(limit), Some(offset)).await?;
     Ok(notes)
 }
 
@@ -1,6 +1,10 @@
-use super::{fetcher, table_adapter::UserTableItem, utils};
+use super::{fetcher, table_adapter::UserTableItem};
 use crate::common::{
-    command_utils::display_results, display_options::DisplayOptions, table::TableDisplayable,
+    command_utils::display_results,
+    display_options::DisplayOptions,
+    paginator::{self, Pagination},
+    sorting,
+    table::TableDisplayable,
 };
 use anyhow::Result;
 
@@ -8,9 +12,10 @@ use anyhow::Result;
 #[derive(Debug, Clone)]
 pub struct UserSearchOptions {
     pub query: String,
-    pub limit: i32,
-    pub offset: i32,
+    pub pagination: Pagination,
     pub developer_type: Option<String>,
+    pub sort: Option<String>,
+    pub reverse: bool,
 }
 
 /// Display a single user in Unix-style format (key: value pairs)
@@ -79,16 +84,30 @@ pub async fn handle_search_action(
     search_options: UserSearchOptions,
     display_options: DisplayOptions,
 ) -> Result<()> {
-    let users = fetcher::search_users(
-        api_config,
-        &search_options.query,
-        search_options.limit,
-        search_options.offset,
-        search_options.developer_type,
-    )
+    // Validate the sort field before fetching
+    let sort_field = search_options
+        .sort
+        .as_deref()
+        .map(sorting::parse_sort_field::<UserTableItem>)
+        .transpose()?;
+
+    let users = paginator::fetch_pages(search_options.pagination, |offset, limit| {
+        fetcher::search_users(
+            api_config,
+            &search_options.query,
+            search_options.developer_type.as_deref(),
+            offset,
+            limit,
+        )
+    })
     .await?;
 
-    let user_items: Vec<UserTableItem> = users.into_iter().map(UserTableItem::from).collect();
+    let mut user_items: Vec<UserTableItem> = users.into_iter().map(UserTableItem::from).collect();
+    match sort_field {
+        Some(field) => sorting::sort_items(&mut user_items, field, search_options.reverse),
+        None if search_options.reverse => user_items.reverse(),
+        None => {}
+    }
 
     display_results(
         &user_items,
@@ -154,22 +173,21 @@ pub async fn handle_note_get_action(
     use_id: bool,
     display_options: DisplayOptions,
 ) -> Result<()> {
-    let (resolved_id, is_user_id) = utils::resolve_user_identifier(identifier, use_id);
-
-    // Get the user ID if not already provided
-    let target_user_id = if is_user_id {
-        resolved_id
-    } else {
-        let search_results = fetcher::search_users(api_config, &resolved_id, 10, 0, None).await?;
-        let matching_user = search_results
-            .into_iter()
-            .find(|u| u.display_name.eq_ignore_ascii_case(&resolved_id))
-            .ok_or_else(|| anyhow::anyhow!("User not found: {}", resolved_id))?;
-        matching_user.id
-    };
+    let target_user_id =
+        crate::common::user_operations::resolve_user_identifier(api_config, identifier, use_id)
+            .await?;
 
     // Get all notes and find the one for this user
-    let notes = fetcher::fetch_user_notes(api_config).await?;
+    let all_pages = Pagination {
+        limit: paginator::PAGE_SIZE,
+        offset: 0,
+        all: true,
+        max: None,
+    };
+    let notes = paginator::fetch_pages(all_pages, |offset, limit| {
+        fetcher::fetch_user_notes(api_config, offset, limit)
+    })
+    .await?;
     let user_note = notes
         .into_iter()
         .find(|note| note.target_user_id == target_user_id);
@@ -196,19 +214,9 @@ pub async fn handle_note_set_action(
     note: &str,
     use_id: bool,
 ) -> Result<()> {
-    let (resolved_id, is_user_id) = utils::resolve_user_identifier(identifier, use_id);
-
-    // Get the user ID if not already provided
-    let target_user_id = if is_user_id {
-        resolved_id
-    } else {
-        let search_results = fetcher::search_users(api_config, &resolved_id, 10, 0, None).await?;
-        let matching_user = search_results
-            .into_iter()
-            .find(|u| u.display_name.eq_ignore_ascii_case(&resolved_id))
-            .ok_or_else(|| anyhow::anyhow!("User not found: {}", resolved_id))?;
-        matching_user.id
-    };
+    let target_user_id =
+        crate::common::user_operations::resolve_user_identifier(api_config, identifier, use_id)
+            .await?;
 
     let updated_note = fetcher::update_user_note(api_config, &target_user_id, note).await?;
     println!(
@@ -222,9 +230,25 @@ pub async fn handle_note_set_action(
 /// Handle the Notes List action
 pub async fn handle_notes_list_action(
     api_config: &vrchatapi::apis::configuration::Configuration,
+    pagination: Pagination,
+    sort: Option<&str>,
+    reverse: bool,
     display_options: DisplayOptions,
 ) -> Result<()> {
-    let notes = fetcher::fetch_user_notes(api_config).await?;
+    // Validate the sort field before fetching
+    let sort_field = sort
+        .map(sorting::parse_sort_field::<vrchatapi::models::UserNote>)
+        .transpose()?;
+
+    let mut notes = paginator::fetch_pages(pagination, |offset, limit| {
+        fetcher::fetch_user_notes(api_config, offset, limit)
+    })
+    .await?;
+    match sort_field {
+        Some(field) => sorting::sort_items(&mut notes, field, reverse),
+        None if reverse => notes.reverse(),
+        None => {}
+    }
 
     if notes.is_empty() {
         if display_options.json {
@@ -254,6 +278,50 @@ pub async fn handle_notes_list_action(
     Ok(())
 }
 
+/// Handle the Names action
+pub async fn handle_names_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    identifier: &str,
+    use_id: bool,
+    json: bool,
+) -> Result<()> {
+    let user_id =
+        crate::common::user_operations::resolve_user_identifier(api_config, identifier, use_id)
+            .await?;
+
+    let store = crate::history::HistoryStore::open()?;
+    let names = store.display_names(&user_id)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&names)?);
+        return Ok(());
+    }
+
+    if names.is_empty() {
+        println!("No display names recorded for user: {identifier}");
+        return Ok(());
+    }
+
+    let name_width = names
+        .iter()
+        .map(|record| unicode_width::UnicodeWidthStr::width(record.display_name.as_str()))
+        .max()
+        .unwrap_or(0)
+        + 2;
+
+    println!("Display names of {user_id}:");
+    for record in names {
+        println!(
+            "  {}{} → {}",
+            crate::common::utils::format_text_with_width(&record.display_name, name_width),
+            crate::common::utils::format_unix_timestamp(record.first_seen),
+            crate::common::utils::format_unix_timestamp(record.last_seen)
+        );
+    }
+
+    Ok(())
+}
+
 /// Handle the Feedback action
 pub async fn handle_feedback_action(
     api_config: &vrchatapi::apis::configuration::Configuration,
@@ -261,19 +329,9 @@ pub async fn handle_feedback_action(
     use_id: bool,
     display_options: DisplayOptions,
 ) -> Result<()> {
-    let (resolved_id, is_user_id) = utils::resolve_user_identifier(identifier, use_id);
-
-    // Get the user ID if not already provided
-    let target_user_id = if is_user_id {
-        resolved_id
-    } else {
-        let search_results = fetcher::search_users(api_config, &resolved_id, 10, 0, None).await?;
-        let matching_user = search_results
-            .into_iter()
-            .find(|u| u.display_name.eq_ignore_ascii_case(&resolved_id))
-            .ok_or_else(|| anyhow::anyhow!("User not found: {}", resolved_id))?;
-        matching_user.id
-    };
+    let target_user_id =
+        crate::common::user_operations::resolve_user_identifier(api_config, identifier, use_id)
+            .await?;
 
     let feedback = fetcher::fetch_user_feedback(api_config, &target_user_id).await?;
 
@@ -1,11 +1,10 @@
 mod fetcher;
 mod handlers;
-mod sorting;
 mod table_adapter;
-mod utils;
 
 use crate::common::auth_client::AuthenticatedClient;
 use crate::common::display_options::DisplayOptions;
+use crate::common::paginator::{self, Pagination};
 use anyhow::Result;
 use handlers::UserSearchOptions;
 use vrcli::UsersAction;
@@ -19,15 +18,25 @@ pub async fn handle_users_command(action: UsersAction) -> Result<()> {
             query,
             limit,
             offset,
+            all,
+            max,
             developer_type,
+            sort,
+            reverse,
             json,
             long,
         } => {
             let options = UserSearchOptions {
                 query,
-                limit,
-                offset,
+                pagination: Pagination {
+                    limit,
+                    offset,
+                    all,
+                    max,
+                },
                 developer_type,
+                sort,
+                reverse,
             };
             let display_options = DisplayOptions {
                 json,
@@ -100,7 +109,14 @@ pub async fn handle_users_command(action: UsersAction) -> Result<()> {
                 } => handlers::handle_note_set_action(api_config, &identifier, &note, id).await,
             }
         }
-        UsersAction::Notes { json, long } => {
+        UsersAction::Notes {
+            sort,
+            reverse,
+            all,
+            max,
+            json,
+            long,
+        } => {
             let display_options = DisplayOptions {
                 json,
                 long_format: long,
@@ -110,7 +126,20 @@ pub async fn handle_users_command(action: UsersAction) -> Result<()> {
                 show_location: false,
                 show_activity: false,
             };
-            handlers::handle_notes_list_action(api_config, display_options).await
+            let pagination = Pagination {
+                limit: paginator::PAGE_SIZE,
+                offset: 0,
+                all,
+                max,
+            };
+            handlers::handle_notes_list_action(
+                api_config,
+                pagination,
+                sort.as_deref(),
+                reverse,
+                display_options,
+            )
+            .await
         }
         UsersAction::Feedback {
             identifier,
@@ -128,6 +157,11 @@ pub async fn handle_users_command(action: UsersAction) -> Result<()> {
             };
             handlers::handle_feedback_action(api_config, &identifier, id, display_options).await
         }
+        UsersAction::Names {
+            identifier,
+            id,
+            json,
+        } => handlers::handle_names_action(api_config, &identifier, id, json).await,
         UsersAction::Diagnose { identifier, id } => {
             crate::common::user_operations::diagnose_user_access_issues(api_config, &identifier, id)
                 .await
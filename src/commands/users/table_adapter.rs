@@ -1,4 +1,5 @@
 use crate::common::output_options::OutputOptions;
+use crate::common::sorting::{SortKey, Sortable};
 use crate::common::table::TableDisplayable;
 use serde_json::{Map, Value};
 
@@ -91,6 +92,40 @@ impl TableDisplayable for UserTableItem {
     }
 }
 
+impl Sortable for UserTableItem {
+    fn sort_fields() -> &'static [&'static str] {
+        &["name", "id", "activity", "joined"]
+    }
+
+    fn sort_key(&self, field: &str) -> SortKey {
+        match field {
+            "id" => SortKey::text(&self.id),
+            "activity" => SortKey::text(&self.last_activity),
+            "joined" => SortKey::text(&self.date_joined),
+            _ => SortKey::text(&self.display_name),
+        }
+    }
+}
+
+impl Sortable for vrchatapi::models::UserNote {
+    fn sort_fields() -> &'static [&'static str] {
+        &["name", "id", "created"]
+    }
+
+    fn sort_key(&self, field: &str) -> SortKey {
+        match field {
+            "id" => SortKey::text(&self.target_user_id),
+            "created" => SortKey::text(&self.created_at),
+            _ => SortKey::text(
+                self.target_user
+                    .as_ref()
+                    .and_then(|user| user.display_name.as_deref())
+                    .unwrap_or_default(),
+            ),
+        }
+    }
+}
+
 /// Convert User model to UserTableItem
 impl From<vrchatapi::models::User> for UserTableItem {
     fn from(user: vrchatapi::models::User) -> Self {
@@ -1,32 +1,141 @@
 use anyhow::Result;
 use vrchatapi::apis::worlds_api;
+use vrchatapi::models::{OrderOption, ReleaseStatus, SortOption};
 
-/// Search worlds by query
+/// Filters for the world search endpoint, already in API form
+#[derive(Debug, Clone, Default)]
+pub struct WorldSearchFilters {
+    pub query: Option<String>,
+    pub sort: Option<SortOption>,
+    pub order: Option<OrderOption>,
+    /// Comma-separated tags the worlds must have
+    pub tags: Option<String>,
+    /// Comma-separated tags the worlds must not have
+    pub exclude_tags: Option<String>,
+    pub platform: Option<String>,
+    pub featured: bool,
+    pub author_id: Option<String>,
+    pub fuzzy: bool,
+    pub release_status: Option<ReleaseStatus>,
+    pub min_unity_version: Option<String>,
+    pub max_unity_version: Option<String>,
+    /// Only worlds authored by the current user
+    pub mine: bool,
+}
+
+/// Search worlds, returning one page of results
 pub async fn search_worlds(
     api_config: &vrchatapi::apis::configuration::Configuration,
-    query: &str,
-    limit: i32,
+    filters: &WorldSearchFilters,
     offset: i32,
+    limit: i32,
 ) -> Result<Vec<vrchatapi::models::LimitedWorld>> {
     let worlds = worlds_api::search_worlds(
+        api_config,
+        filters.featured.then_some(true),
+        filters.sort,
+        filters.mine.then_some("me"),
+        filters.author_id.as_deref(),
+        Some(limit),
+        filters.order,
+        Some(offset),
+        filters.query.as_deref(),
+        filters.tags.as_deref(),
+        filters.exclude_tags.as_deref(),
+        filters.release_status,
+        filters.max_unity_version.as_deref(),
+        filters.min_unity_version.as_deref(),
+        filters.platform.as_deref(),
+        filters.fuzzy.then_some(true),
+    )
+    .await?;
+
+    Ok(worlds)
+}
+
+/// Which listing endpoint to fetch worlds from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorldListing {
+    Active,
+    Recent,
+}
+
+/// Fetch worlds from the active or recent listing
+pub async fn fetch_world_listing(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    listing: WorldListing,
+    offset: i32,
+    limit: i32,
+) -> Result<Vec<vrchatapi::models::LimitedWorld>> {
+    let worlds = match listing {
+        WorldListing::Active => {
+            worlds_api::get_active_worlds(
+                api_config,
+                None, // featured
+                None, // sort
+                Some(limit),
+                None, // order
+                Some(offset),
+                None, // search
+                None, // tag
+                None, // notag
+                None, // release_status
+                None, // max_unity_version
+                None, // min_unity_version
+                None, // platform
+            )
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to fetch active worlds: {}", e))?
+        }
+        WorldListing::Recent => {
+            worlds_api::get_recent_worlds(
+                api_config,
+                None, // featured
+                None, // sort
+                Some(limit),
+                None, // order
+                Some(offset),
+                None, // search
+                None, // tag
+                None, // notag
+                None, // release_status
+                None, // max_unity_version
+                None, // min_unity_version
+                None, // platform
+                None, // user_id - the current user
+            )
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to fetch recent worlds: {}", e))?
+        }
+    };
+
+    Ok(worlds)
+}
+
+/// Fetch the current user's favorite worlds
+pub async fn fetch_favorited_worlds(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    offset: i32,
+    limit: i32,
+) -> Result<Vec<vrchatapi::models::FavoritedWorld>> {
+    let worlds = worlds_api::get_favorited_worlds(
         api_config,
         None, // featured
         None, // sort
-        None, // user
-        None, // user_id
         Some(limit),
         None, // order
         Some(offset),
-        Some(query),
+        None, // search
         None, // tag
         None, // notag
         None, // release_status
         None, // max_unity_version
         None, // min_unity_version
         None, // platform
-        None, // fuzzy
+        None, // user_id - the current user
     )
-    .await?;
+    .await
+    .map_err(|e| anyhow::anyhow!("Failed to fetch favorite worlds: {}", e))?;
 
     Ok(worlds)
 }
@@ -59,3 +168,61 @@ pub async fn fetch_world_by_id(
         })?;
     Ok(world)
 }
+
+/// Convert a worlds API error from a change to a world into a user-friendly message
+fn world_change_error<T>(
+    action: &str,
+    world_id: &str,
+    e: vrchatapi::apis::Error<T>,
+) -> anyhow::Error {
+    match e {
+        vrchatapi::apis::Error::ResponseError(ref response_content) => {
+            match response_content.status.as_u16() {
+                404 => anyhow::anyhow!("No world found with ID '{}'", world_id),
+                401 | 403 => anyhow::anyhow!(
+                    "Failed to {} world '{}' - only the author can change it",
+                    action,
+                    world_id
+                ),
+                status => anyhow::anyhow!(
+                    "Failed to {} world '{}' - HTTP {}",
+                    action,
+                    world_id,
+                    status
+                ),
+            }
+        }
+        _ => anyhow::anyhow!("Failed to {} world '{}' - {}", action, world_id, e),
+    }
+}
+
+/// Update the metadata of a world authored by the current user
+pub async fn update_world(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    world_id: &str,
+    request: vrchatapi::models::UpdateWorldRequest,
+) -> Result<vrchatapi::models::World> {
+    worlds_api::update_world(api_config, world_id, Some(request))
+        .await
+        .map_err(|e| world_change_error("update", world_id, e))
+}
+
+/// Publish a world to Community Labs
+pub async fn publish_world(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    world_id: &str,
+) -> Result<()> {
+    worlds_api::publish_world(api_config, world_id)
+        .await
+        .map_err(|e| world_change_error("publish", world_id, e))
+}
+
+/// Take a world out of Community Labs and public listings
+pub async fn unpublish_world(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    world_id: &str,
+) -> Result<()> {
+    worlds_api::unpublish_world(api_config, world_id)
+        .await
+        .map_err(|e| world_change_error("unpublish", world_id, e))
+}
@@ -1,16 +1,70 @@
-use super::{fetcher, table_adapter::WorldTableItem};
+use super::{
+    fetcher,
+    table_adapter::{InstanceTableItem, WorldTableItem},
+};
 use crate::common::{
-    command_utils::display_results, display_options::DisplayOptions, table::TableDisplayable,
-    world_tags,
+    command_utils::display_results,
+    content_policy::{self, ContentFilter},
+    display_options::DisplayOptions,
+    favorites,
+    location::{self, Location},
+    paginator::{self, Pagination},
+    sorting::{self, Sortable},
+    table::TableDisplayable,
+    world_tags::{self, TagFilter},
 };
 use anyhow::Result;
+use vrchatapi::models::FavoriteType;
 
 /// Configuration for world search options
 #[derive(Debug, Clone)]
 pub struct WorldSearchOptions {
-    pub query: String,
-    pub limit: i32,
-    pub offset: i32,
+    pub query: Option<String>,
+    pub pagination: Pagination,
+    pub sort: Option<String>,
+    pub reverse: bool,
+    pub order: Option<String>,
+    pub tags: Vec<String>,
+    pub exclude_tags: Vec<String>,
+    pub platform: Option<String>,
+    pub featured: bool,
+    pub author: Option<String>,
+    pub fuzzy: bool,
+    pub release_status: Option<String>,
+    pub min_unity_version: Option<String>,
+    pub max_unity_version: Option<String>,
+    pub tag_filter: TagFilter,
+    pub content_filter: ContentFilter,
+}
+
+/// Sort options accepted by the world search endpoint
+const SORT_OPTIONS: &[&str] = &[
+    "popularity",
+    "heat",
+    "trust",
+    "shuffle",
+    "random",
+    "favorites",
+    "publicationDate",
+    "labsPublicationDate",
+    "created",
+    "updated",
+    "order",
+];
+
+/// Parse a string into one of the API's enum types by its wire name
+fn parse_api_value<T: serde::de::DeserializeOwned>(value: &str) -> Option<T> {
+    serde_json::from_value(serde_json::Value::String(value.to_string())).ok()
+}
+
+/// Join repeated tag flags into the comma-separated form the API expects
+fn join_tags(tags: &[String]) -> Option<String> {
+    let tags: Vec<&str> = tags
+        .iter()
+        .map(|tag| tag.trim())
+        .filter(|tag| !tag.is_empty())
+        .collect();
+    (!tags.is_empty()).then(|| tags.join(","))
 }
 
 /// Handle the Search action
@@ -19,23 +73,356 @@ pub async fn handle_search_action(
     search_options: WorldSearchOptions,
     display_options: DisplayOptions,
 ) -> Result<()> {
-    let worlds = fetcher::search_worlds(
-        api_config,
-        &search_options.query,
-        search_options.limit,
-        search_options.offset,
-    )
+    // Sorts the API knows are passed on so the right pages are fetched; fields it does not
+    // know are sorted locally, and fields both know get both
+    let api_sort = search_options.sort.as_deref().and_then(parse_api_value);
+    let local_sort = search_options
+        .sort
+        .as_deref()
+        .and_then(|field| sorting::parse_sort_field::<WorldTableItem>(field).ok());
+    if let (Some(sort), None, None) = (&search_options.sort, &api_sort, local_sort) {
+        let local_fields = WorldTableItem::sort_fields();
+        let mut valid: Vec<&str> = local_fields.to_vec();
+        valid.extend(
+            SORT_OPTIONS
+                .iter()
+                .filter(|option| !local_fields.contains(option)),
+        );
+        return Err(anyhow::anyhow!(
+            "Unknown sort '{}'. Valid sorts: {}",
+            sort,
+            valid.join(", ")
+        ));
+    }
+
+    let author_id = match &search_options.author {
+        Some(author) => Some(
+            crate::common::user_operations::resolve_user_identifier(api_config, author, false)
+                .await?,
+        ),
+        None => None,
+    };
+
+    let filters = fetcher::WorldSearchFilters {
+        query: search_options.query.clone(),
+        sort: api_sort,
+        // Both are restricted to valid values on the command line
+        order: search_options.order.as_deref().and_then(parse_api_value),
+        tags: join_tags(&search_options.tags),
+        exclude_tags: join_tags(&search_options.exclude_tags),
+        platform: search_options.platform.clone(),
+        featured: search_options.featured,
+        author_id,
+        fuzzy: search_options.fuzzy,
+        release_status: search_options
+            .release_status
+            .as_deref()
+            .and_then(parse_api_value),
+        min_unity_version: search_options.min_unity_version.clone(),
+        max_unity_version: search_options.max_unity_version.clone(),
+        mine: false,
+    };
+
+    let worlds = paginator::fetch_pages(search_options.pagination, |offset, limit| {
+        fetcher::search_worlds(api_config, &filters, offset, limit)
+    })
     .await?;
 
+    let mut world_items: Vec<WorldTableItem> = worlds
+        .into_iter()
+        .map(WorldTableItem::from)
+        .filter(|world| search_options.tag_filter.matches(&world.tags))
+        .collect();
+    let hidden = search_options
+        .content_filter
+        .retain(&mut world_items, |world| &world.tags);
+    match local_sort {
+        Some(field) => sorting::sort_items(&mut world_items, field, search_options.reverse),
+        None if search_options.reverse => world_items.reverse(),
+        None => {}
+    }
+
+    let empty_message = match &search_options.query {
+        Some(query) => format!("No worlds found for query: {query}"),
+        None => "No worlds found matching the filters".to_string(),
+    };
+    display_results(&world_items, &display_options, &empty_message)?;
+    content_policy::print_hidden_footer(hidden, "world(s)", display_options.json);
+    Ok(())
+}
+
+/// Metadata changes requested by the Update action
+#[derive(Debug, Clone, Default)]
+pub struct WorldUpdateOptions {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub capacity: Option<i32>,
+    pub release_status: Option<String>,
+}
+
+/// Handle the Mine action: worlds authored by the current user, newest update first
+pub async fn handle_mine_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    release_status: &str,
+    pagination: Pagination,
+    display_options: DisplayOptions,
+) -> Result<()> {
+    let filters = fetcher::WorldSearchFilters {
+        sort: Some(vrchatapi::models::SortOption::Updated),
+        order: Some(vrchatapi::models::OrderOption::Descending),
+        // Restricted to valid values on the command line
+        release_status: parse_api_value(release_status),
+        mine: true,
+        ..Default::default()
+    };
+
+    let worlds = paginator::fetch_pages(pagination, |offset, limit| {
+        fetcher::search_worlds(api_config, &filters, offset, limit)
+    })
+    .await?;
     let world_items: Vec<WorldTableItem> = worlds.into_iter().map(WorldTableItem::from).collect();
 
     display_results(
         &world_items,
         &display_options,
-        &format!(
-            "No worlds found for query: {query}",
-            query = search_options.query
+        "You have not uploaded any worlds",
+    )
+}
+
+/// Handle the Update action: change the metadata of one of the current user's worlds
+pub async fn handle_update_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    world_id: &str,
+    options: WorldUpdateOptions,
+    display_options: DisplayOptions,
+) -> Result<()> {
+    if options.name.is_none()
+        && options.description.is_none()
+        && options.tags.is_none()
+        && options.capacity.is_none()
+        && options.release_status.is_none()
+    {
+        return Err(anyhow::anyhow!(
+            "Nothing to update. Pass --name, --description, --tags, --capacity or --release-status"
+        ));
+    }
+
+    let mut request = vrchatapi::models::UpdateWorldRequest::new();
+    request.name = options.name;
+    request.description = options.description;
+    request.capacity = options.capacity;
+    // Restricted to valid values on the command line
+    request.release_status = options.release_status.as_deref().and_then(parse_api_value);
+    if let Some(tags) = &options.tags {
+        // The API replaces the whole tag list, so keep the tags the author does not set
+        let world = fetcher::fetch_world_by_id(api_config, world_id).await?;
+        request.tags = Some(world_tags::replace_author_tags(&world.tags, tags)?);
+    }
+
+    let world = fetcher::update_world(api_config, world_id, request).await?;
+    let release_status = serde_json::to_value(world.release_status)?;
+    let release_status = release_status.as_str().unwrap_or("unknown").to_string();
+    let world_item = WorldTableItem::from(world);
+
+    if display_options.json {
+        let mut json_obj = world_item.to_json_object(&display_options.to_output_options());
+        if let Some(map) = json_obj.as_object_mut() {
+            map.insert("release_status".to_string(), release_status.into());
+        }
+        println!("{}", serde_json::to_string_pretty(&json_obj)?);
+        return Ok(());
+    }
+
+    println!("✅ Updated {} ({})", world_item.name, world_item.id);
+    if !world_item.description.is_empty() && world_item.description != "N/A" {
+        println!("Description: {}", world_item.description);
+    }
+    println!("Capacity: {}", world_item.capacity);
+    println!("Release Status: {release_status}");
+    println!("Tags: {}", world_tags::format_world_tags(&world_item.tags));
+    Ok(())
+}
+
+/// Handle the Publish and Unpublish actions
+pub async fn handle_publish_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    world_id: &str,
+    publish: bool,
+) -> Result<()> {
+    let world = fetcher::fetch_world_by_id(api_config, world_id).await?;
+    if publish {
+        fetcher::publish_world(api_config, world_id).await?;
+        println!(
+            "✅ Published {} ({}) to Community Labs",
+            world.name, world.id
+        );
+    } else {
+        fetcher::unpublish_world(api_config, world_id).await?;
+        println!("✅ Unpublished {} ({})", world.name, world.id);
+    }
+    Ok(())
+}
+
+/// Handle the Active and Recent actions
+pub async fn handle_listing_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    listing: fetcher::WorldListing,
+    pagination: Pagination,
+    tag_filter: &TagFilter,
+    content_filter: &ContentFilter,
+    display_options: DisplayOptions,
+) -> Result<()> {
+    let worlds = paginator::fetch_pages(pagination, |offset, limit| {
+        fetcher::fetch_world_listing(api_config, listing, offset, limit)
+    })
+    .await?;
+    let mut world_items: Vec<WorldTableItem> = worlds
+        .into_iter()
+        .map(WorldTableItem::from)
+        .filter(|world| tag_filter.matches(&world.tags))
+        .collect();
+    let hidden = content_filter.retain(&mut world_items, |world| &world.tags);
+
+    let empty_message = match listing {
+        fetcher::WorldListing::Active => "No active worlds found",
+        fetcher::WorldListing::Recent => "No recently visited worlds found",
+    };
+    display_results(&world_items, &display_options, empty_message)?;
+    content_policy::print_hidden_footer(hidden, "world(s)", display_options.json);
+    Ok(())
+}
+
+/// Handle the Favorites action
+pub async fn handle_favorites_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    group: Option<&str>,
+    pagination: Pagination,
+    tag_filter: &TagFilter,
+    content_filter: &ContentFilter,
+    display_options: DisplayOptions,
+) -> Result<()> {
+    // Resolve the group first so a typo lists the available groups
+    let group_name = match group {
+        Some(group) => Some(
+            favorites::find_favorite_group(api_config, FavoriteType::World, group)
+                .await?
+                .name,
         ),
+        None => None,
+    };
+
+    // The API cannot filter by group, so a group is picked out of every favorite and the
+    // offset and limit apply to the worlds in it
+    let fetch_pagination = match group_name {
+        Some(_) => Pagination {
+            offset: 0,
+            all: true,
+            max: None,
+            ..pagination
+        },
+        None => pagination,
+    };
+    let worlds = paginator::fetch_pages(fetch_pagination, |offset, limit| {
+        fetcher::fetch_favorited_worlds(api_config, offset, limit)
+    })
+    .await?;
+    let mut worlds: Vec<_> = worlds
+        .into_iter()
+        .filter(|world| {
+            group_name
+                .as_ref()
+                .is_none_or(|name| world.favorite_group == *name)
+        })
+        .collect();
+    if group_name.is_some() {
+        let take = match (pagination.max, pagination.all) {
+            (Some(max), _) => max,
+            (None, true) => usize::MAX,
+            (None, false) => pagination.limit.max(0) as usize,
+        };
+        worlds = worlds
+            .into_iter()
+            .skip(pagination.offset.max(0) as usize)
+            .take(take)
+            .collect();
+    }
+
+    let mut world_items: Vec<WorldTableItem> = worlds
+        .into_iter()
+        .map(WorldTableItem::from)
+        .filter(|world| tag_filter.matches(&world.tags))
+        .collect();
+    let hidden = content_filter.retain(&mut world_items, |world| &world.tags);
+
+    display_results(&world_items, &display_options, "No favorite worlds found")?;
+    content_policy::print_hidden_footer(hidden, "world(s)", display_options.json);
+    Ok(())
+}
+
+/// Handle the Instances action
+pub async fn handle_instances_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    world_id: &str,
+    content_filter: &ContentFilter,
+    display_options: DisplayOptions,
+) -> Result<()> {
+    let world = fetcher::fetch_world_by_id(api_config, world_id).await?;
+    content_filter.ensure_allowed(&world)?;
+    let online_friends =
+        crate::commands::friends::fetcher::fetch_pages_parallel(api_config, Some(false), None)
+            .await?;
+
+    // Each entry is `[instance_id, user_count]`
+    let mut instance_items: Vec<InstanceTableItem> = world
+        .instances
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|entry| {
+            let instance_id = entry.first()?.as_str()?;
+            let user_count = entry.get(1).and_then(|count| count.as_i64()).unwrap_or(0);
+            let Ok(Location::Instance(instance)) =
+                location::parse_location(&format!("{}:{}", world.id, instance_id))
+            else {
+                return None;
+            };
+
+            let friends: Vec<String> = online_friends
+                .iter()
+                .filter(|friend| {
+                    matches!(
+                        location::parse_location(&friend.location),
+                        Ok(Location::Instance(at)) if at.location() == instance.location()
+                    )
+                })
+                .map(|friend| friend.display_name.clone())
+                .collect();
+
+            Some(InstanceTableItem {
+                location: instance.location(),
+                name: instance.name.clone(),
+                access: instance.access.to_string(),
+                region: instance.region.clone(),
+                user_count: user_count as i32,
+                world_capacity: world.capacity,
+                friends_summary: friends.join(", "),
+                friends,
+            })
+        })
+        .collect();
+
+    // Instances with friends inside first, then the busiest
+    instance_items.sort_by(|a, b| {
+        a.friends
+            .is_empty()
+            .cmp(&b.friends.is_empty())
+            .then(b.user_count.cmp(&a.user_count))
+    });
+
+    display_results(
+        &instance_items,
+        &display_options,
+        &format!("No public instances of {} are open", world.name),
     )
 }
 
@@ -43,13 +430,26 @@ pub async fn handle_search_action(
 pub async fn handle_get_action(
     api_config: &vrchatapi::apis::configuration::Configuration,
     world_id: &str,
+    show_link: bool,
+    content_filter: &ContentFilter,
     display_options: DisplayOptions,
 ) -> Result<()> {
     let world = fetcher::fetch_world_by_id(api_config, world_id).await?;
+    content_filter.ensure_allowed(&world)?;
     let world_item = WorldTableItem::from(world);
 
     if display_options.json {
-        let json_obj = world_item.to_json_object(&display_options.to_output_options());
+        let mut json_obj = world_item.to_json_object(&display_options.to_output_options());
+        if let (true, Some(map)) = (show_link, json_obj.as_object_mut()) {
+            map.insert(
+                "launch_url".to_string(),
+                location::launch_uri(&world_item.id, None).into(),
+            );
+            map.insert(
+                "web_url".to_string(),
+                location::web_launch_url(&world_item.id, None).into(),
+            );
+        }
         println!("{}", serde_json::to_string_pretty(&json_obj)?);
         return Ok(());
     }
@@ -91,5 +491,109 @@ pub async fn handle_get_action(
     println!("Created: {}", world_item.created_at);
     println!("Updated: {}", world_item.updated_at);
 
+    if show_link {
+        println!("Launch: {}", location::launch_uri(&world_item.id, None));
+        println!("Web: {}", location::web_launch_url(&world_item.id, None));
+    }
+
     Ok(())
 }
+
+/// Handle the Tags action
+pub fn handle_tags_action(category: Option<&str>, json: bool) -> Result<()> {
+    let tags: Vec<world_tags::KnownTag> = world_tags::known_tags()
+        .into_iter()
+        .filter(|tag| category.is_none_or(|wanted| tag.category.eq_ignore_ascii_case(wanted)))
+        .collect();
+
+    if tags.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Unknown tag category '{}'. Valid categories: author, feature, content, language, system, admin, platform",
+            category.unwrap_or_default()
+        ));
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&tags)?);
+        return Ok(());
+    }
+
+    let width = tags.iter().map(|tag| tag.tag.len()).max().unwrap_or(0);
+    let mut current_category = "";
+    for tag in &tags {
+        if tag.category != current_category {
+            if !current_category.is_empty() {
+                println!();
+            }
+            println!("{}:", tag.category);
+            current_category = tag.category;
+        }
+        println!("  {:<width$}  {}", tag.tag, tag.name);
+    }
+
+    Ok(())
+}
+
+/// Handle the Link action
+pub fn handle_link_action(
+    world: &str,
+    instance: Option<&str>,
+    open_target: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    let (world_id, instance_id) = split_world_and_instance(world, instance)?;
+    let launch_url = location::launch_uri(&world_id, instance_id.as_deref());
+    let web_url = location::web_launch_url(&world_id, instance_id.as_deref());
+
+    if json {
+        let json_obj = serde_json::json!({
+            "world_id": world_id,
+            "instance_id": instance_id,
+            "launch_url": launch_url,
+            "web_url": web_url,
+        });
+        println!("{}", serde_json::to_string_pretty(&json_obj)?);
+    } else {
+        println!("Launch: {launch_url}");
+        println!("Web: {web_url}");
+    }
+
+    if let Some(target) = open_target {
+        let url = if target == "web" {
+            &web_url
+        } else {
+            &launch_url
+        };
+        open::that(url).map_err(|e| anyhow::anyhow!("Failed to open {}: {}", url, e))?;
+    }
+
+    Ok(())
+}
+
+/// Accept either a world ID plus optional instance, or a full location
+fn split_world_and_instance(
+    world: &str,
+    instance: Option<&str>,
+) -> Result<(String, Option<String>)> {
+    if world.contains(':') {
+        let Location::Instance(parsed) = location::parse_location(world)? else {
+            return Err(anyhow::anyhow!("'{}' is not a world instance", world));
+        };
+        if instance.is_some() {
+            return Err(anyhow::anyhow!(
+                "'{}' already names an instance; drop --instance",
+                world
+            ));
+        }
+        return Ok((parsed.world_id, Some(parsed.instance_id)));
+    }
+
+    if !world.starts_with("wrld_") {
+        return Err(anyhow::anyhow!(
+            "Invalid world ID '{}': world IDs start with 'wrld_'",
+            world
+        ));
+    }
+
+    Ok((world.to_string(), instance.map(str::to_string)))
+}
@@ -1,29 +1,82 @@
 mod fetcher;
 mod handlers;
 mod table_adapter;
+mod watch;
 
 use crate::common::auth_client::AuthenticatedClient;
+use crate::common::content_policy::ContentFilter;
 use crate::common::display_options::DisplayOptions;
+use crate::common::paginator::Pagination;
+use crate::common::world_tags::TagFilter;
 use anyhow::Result;
-use handlers::WorldSearchOptions;
+use fetcher::WorldListing;
+use handlers::{WorldSearchOptions, WorldUpdateOptions};
 use vrcli::WorldsAction;
 
 pub async fn handle_worlds_command(action: WorldsAction) -> Result<()> {
-    let auth_client = AuthenticatedClient::new().await?;
-    let api_config = auth_client.api_config();
-
     match action {
+        // Links, the tag catalogue and exports are built locally, so they work without logging in
+        WorldsAction::Link {
+            world,
+            instance,
+            open,
+            json,
+        } => handlers::handle_link_action(&world, instance.as_deref(), open.as_deref(), json),
+        WorldsAction::Tags { category, json } => {
+            handlers::handle_tags_action(category.as_deref(), json)
+        }
+        WorldsAction::Export {
+            world_id,
+            since,
+            output,
+        } => watch::handle_export_action(&world_id, since, output.as_deref()),
         WorldsAction::Search {
             query,
             limit,
             offset,
+            all,
+            max,
+            sort,
+            reverse,
+            order,
+            tag,
+            exclude_tag,
+            platform,
+            featured,
+            author,
+            fuzzy,
+            release_status,
+            min_unity_version,
+            max_unity_version,
+            has_tag,
+            lacks_tag,
+            show_all,
             json,
             long,
         } => {
+            let auth_client = AuthenticatedClient::new().await?;
             let search_options = WorldSearchOptions {
-                query: query.clone(),
-                limit,
-                offset,
+                query,
+                pagination: Pagination {
+                    limit,
+                    offset,
+                    all,
+                    max,
+                },
+                sort,
+                reverse,
+                order,
+                tags: tag,
+                exclude_tags: exclude_tag,
+                platform,
+                featured,
+                author,
+                fuzzy,
+                release_status,
+                min_unity_version,
+                max_unity_version,
+                tag_filter: TagFilter::new(&has_tag, &lacks_tag)?,
+                content_filter: ContentFilter::load(show_all)?,
             };
 
             let display_options = DisplayOptions::from_flags(
@@ -36,9 +89,128 @@ pub async fn handle_worlds_command(action: WorldsAction) -> Result<()> {
                 json,  // json
             );
 
-            handlers::handle_search_action(api_config, search_options, display_options).await
+            handlers::handle_search_action(
+                auth_client.api_config(),
+                search_options,
+                display_options,
+            )
+            .await
+        }
+        WorldsAction::Active {
+            limit,
+            offset,
+            all,
+            max,
+            has_tag,
+            lacks_tag,
+            show_all,
+            json,
+            long,
+        } => {
+            let auth_client = AuthenticatedClient::new().await?;
+            handlers::handle_listing_action(
+                auth_client.api_config(),
+                WorldListing::Active,
+                Pagination {
+                    limit,
+                    offset,
+                    all,
+                    max,
+                },
+                &TagFilter::new(&has_tag, &lacks_tag)?,
+                &ContentFilter::load(show_all)?,
+                listing_display_options(long, json),
+            )
+            .await
+        }
+        WorldsAction::Recent {
+            limit,
+            offset,
+            all,
+            max,
+            has_tag,
+            lacks_tag,
+            show_all,
+            json,
+            long,
+        } => {
+            let auth_client = AuthenticatedClient::new().await?;
+            handlers::handle_listing_action(
+                auth_client.api_config(),
+                WorldListing::Recent,
+                Pagination {
+                    limit,
+                    offset,
+                    all,
+                    max,
+                },
+                &TagFilter::new(&has_tag, &lacks_tag)?,
+                &ContentFilter::load(show_all)?,
+                listing_display_options(long, json),
+            )
+            .await
         }
-        WorldsAction::Get { world_id, json } => {
+        WorldsAction::Favorites {
+            group,
+            limit,
+            offset,
+            all,
+            max,
+            has_tag,
+            lacks_tag,
+            show_all,
+            json,
+            long,
+        } => {
+            let auth_client = AuthenticatedClient::new().await?;
+            handlers::handle_favorites_action(
+                auth_client.api_config(),
+                group.as_deref(),
+                Pagination {
+                    limit,
+                    offset,
+                    all,
+                    max,
+                },
+                &TagFilter::new(&has_tag, &lacks_tag)?,
+                &ContentFilter::load(show_all)?,
+                listing_display_options(long, json),
+            )
+            .await
+        }
+        WorldsAction::Instances {
+            world_id,
+            show_all,
+            json,
+            long,
+        } => {
+            let content_filter = ContentFilter::load(show_all)?;
+            let auth_client = AuthenticatedClient::new().await?;
+            let display_options = DisplayOptions::from_flags(
+                long, // long_format
+                long, // show_id (location) - show when long format is enabled
+                true, // show_status (region)
+                true, // show_platform (users)
+                true, // show_location (friends)
+                true, // show_activity (type)
+                json, // json
+            );
+            handlers::handle_instances_action(
+                auth_client.api_config(),
+                &world_id,
+                &content_filter,
+                display_options,
+            )
+            .await
+        }
+        WorldsAction::Get {
+            world_id,
+            link,
+            show_all,
+            json,
+        } => {
+            let content_filter = ContentFilter::load(show_all)?;
+            let auth_client = AuthenticatedClient::new().await?;
             let display_options = DisplayOptions::from_flags(
                 false, // long_format - will be set by get handler
                 false, // show_id
@@ -48,7 +220,115 @@ pub async fn handle_worlds_command(action: WorldsAction) -> Result<()> {
                 false, // show_activity
                 json,  // json
             );
-            handlers::handle_get_action(api_config, &world_id, display_options).await
+            handlers::handle_get_action(
+                auth_client.api_config(),
+                &world_id,
+                link,
+                &content_filter,
+                display_options,
+            )
+            .await
+        }
+        WorldsAction::Mine {
+            release_status,
+            limit,
+            offset,
+            all,
+            max,
+            json,
+            long,
+        } => {
+            let auth_client = AuthenticatedClient::new().await?;
+            let display_options = DisplayOptions::from_flags(
+                long,  // long_format
+                long,  // show_id - show when long format is enabled
+                false, // show_status (author) - always the current user
+                true,  // show_platform (capacity)
+                long,  // show_location (tags) - show when long format is enabled
+                false, // show_activity (visits) - not reported by search
+                json,  // json
+            );
+            handlers::handle_mine_action(
+                auth_client.api_config(),
+                &release_status,
+                Pagination {
+                    limit,
+                    offset,
+                    all,
+                    max,
+                },
+                display_options,
+            )
+            .await
+        }
+        WorldsAction::Update {
+            world_id,
+            name,
+            description,
+            tags,
+            capacity,
+            release_status,
+            json,
+        } => {
+            let auth_client = AuthenticatedClient::new().await?;
+            let options = WorldUpdateOptions {
+                name,
+                description,
+                tags,
+                capacity,
+                release_status,
+            };
+            let display_options = DisplayOptions::from_flags(
+                false, // long_format
+                false, // show_id
+                false, // show_status
+                false, // show_platform
+                false, // show_location
+                false, // show_activity
+                json,  // json
+            );
+            handlers::handle_update_action(
+                auth_client.api_config(),
+                &world_id,
+                options,
+                display_options,
+            )
+            .await
+        }
+        WorldsAction::Publish { world_id } => {
+            let auth_client = AuthenticatedClient::new().await?;
+            handlers::handle_publish_action(auth_client.api_config(), &world_id, true).await
+        }
+        WorldsAction::Unpublish { world_id } => {
+            let auth_client = AuthenticatedClient::new().await?;
+            handlers::handle_publish_action(auth_client.api_config(), &world_id, false).await
+        }
+        WorldsAction::Watch {
+            world_ids,
+            interval,
+            spike,
+            json,
+        } => {
+            let auth_client = AuthenticatedClient::new().await?;
+            let options = watch::WorldWatchOptions {
+                interval,
+                spike,
+                json,
+            };
+            watch::handle_watch_action(auth_client.api_config(), &world_ids, options).await
         }
     }
 }
+
+/// Display options for world listings, which always show player counts
+fn listing_display_options(long: bool, json: bool) -> DisplayOptions {
+    DisplayOptions::from_flags(
+        long,  // long_format
+        long,  // show_id - show when long format is enabled
+        true,  // show_status (author)
+        true,  // show_platform (players/capacity)
+        long,  // show_location (tags) - show when long format is enabled
+        false, // show_activity (visits) - not reported by listings
+        json,  // json
+    )
+}
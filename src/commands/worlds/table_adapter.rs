@@ -1,4 +1,5 @@
 use crate::common::output_options::OutputOptions;
+use crate::common::sorting::{SortKey, Sortable};
 use crate::common::table::{TableColumnNames, TableDisplayable};
 use crate::common::world_tags;
 use serde_json::{Map, Value};
@@ -47,8 +48,11 @@ impl TableDisplayable for WorldTableItem {
     }
 
     fn formatted_platform(&self) -> Option<String> {
-        // Display capacity as "current/max" format
-        Some(format!("{}", self.capacity))
+        // Display capacity as "current/max" format when player counts are known
+        match self.occupants {
+            Some(occupants) => Some(format!("{occupants}/{}", self.capacity)),
+            None => Some(format!("{}", self.capacity)),
+        }
     }
 
     fn location(&self) -> Option<&str> {
@@ -82,7 +86,11 @@ impl TableDisplayable for WorldTableItem {
             name: "Name",
             id: "ID",
             status: "Author",
-            platform: "Capacity",
+            platform: if self.occupants.is_some() {
+                "Players"
+            } else {
+                "Capacity"
+            },
             location: "Tags",
             activity: "Visits",
         }
@@ -98,6 +106,9 @@ impl TableDisplayable for WorldTableItem {
             Value::String(self.author_name.clone()),
         );
         map.insert("capacity".to_string(), Value::Number(self.capacity.into()));
+        if let Some(occupants) = self.occupants {
+            map.insert("occupants".to_string(), Value::Number(occupants.into()));
+        }
 
         // If JSON output is requested, include all columns
         if options.json {
@@ -193,6 +204,32 @@ impl TableDisplayable for WorldTableItem {
     }
 }
 
+impl Sortable for WorldTableItem {
+    fn sort_fields() -> &'static [&'static str] {
+        &[
+            "name",
+            "id",
+            "visits",
+            "favorites",
+            "capacity",
+            "occupants",
+            "updated",
+        ]
+    }
+
+    fn sort_key(&self, field: &str) -> SortKey {
+        match field {
+            "id" => SortKey::text(&self.id),
+            "visits" => SortKey::number(self.visits_available.then_some(self.visits as i64)),
+            "favorites" => SortKey::Number(self.favorites as i64),
+            "capacity" => SortKey::Number(self.capacity as i64),
+            "occupants" => SortKey::number(self.occupants.map(i64::from)),
+            "updated" => SortKey::text(&self.updated_at),
+            _ => SortKey::text(&self.name),
+        }
+    }
+}
+
 /// Convert LimitedWorld model to WorldTableItem
 impl From<vrchatapi::models::LimitedWorld> for WorldTableItem {
     fn from(world: vrchatapi::models::LimitedWorld) -> Self {
@@ -215,7 +252,36 @@ impl From<vrchatapi::models::LimitedWorld> for WorldTableItem {
             favorites: world.favorites,
             created_at: world.created_at,
             updated_at: world.updated_at,
-            occupants: None, // LimitedWorld doesn't have occupant data
+            occupants: Some(world.occupants),
+            private_occupants: None, // LimitedWorld only has the total
+            public_occupants: None,
+        }
+    }
+}
+
+/// Convert FavoritedWorld model to WorldTableItem
+impl From<vrchatapi::models::FavoritedWorld> for WorldTableItem {
+    fn from(world: vrchatapi::models::FavoritedWorld) -> Self {
+        let (visits, visits_available) = if let Some(v) = world.visits {
+            (v, true)
+        } else {
+            (0, false)
+        };
+
+        WorldTableItem {
+            id: world.id,
+            name: world.name,
+            author_id: world.author_id,
+            author_name: world.author_name,
+            capacity: world.capacity,
+            description: world.description,
+            tags: world.tags,
+            visits,
+            visits_available,
+            favorites: world.favorites,
+            created_at: world.created_at,
+            updated_at: world.updated_at,
+            occupants: Some(world.occupants),
             private_occupants: None,
             public_occupants: None,
         }
@@ -244,3 +310,89 @@ impl From<vrchatapi::models::World> for WorldTableItem {
         }
     }
 }
+
+/// Adapter for converting a live world instance to table format
+pub struct InstanceTableItem {
+    pub location: String,
+    pub name: String,
+    pub access: String,
+    /// `None` when the location has no region modifier
+    pub region: Option<String>,
+    pub user_count: i32,
+    /// The world's soft capacity; the instance listing does not include per-instance limits
+    pub world_capacity: i32,
+    pub friends: Vec<String>,
+    pub friends_summary: String,
+}
+
+impl TableDisplayable for InstanceTableItem {
+    fn display_name(&self) -> &str {
+        &self.name
+    }
+
+    fn id(&self) -> Option<&str> {
+        Some(&self.location)
+    }
+
+    fn status(&self) -> Option<String> {
+        // Reuse status field for the region
+        Some(self.region.clone().unwrap_or_else(|| "unknown".to_string()))
+    }
+
+    fn formatted_platform(&self) -> Option<String> {
+        // Reuse platform field for the user count against the world's soft capacity
+        Some(format!("{}/{}", self.user_count, self.world_capacity))
+    }
+
+    fn location(&self) -> Option<&str> {
+        // Reuse location field for the friends inside
+        Some(&self.friends_summary)
+    }
+
+    fn activity(&self) -> Option<&str> {
+        // Reuse activity field for the instance type
+        Some(&self.access)
+    }
+
+    fn column_names(&self) -> TableColumnNames {
+        TableColumnNames {
+            name: "Instance",
+            id: "Location",
+            status: "Region",
+            platform: "Users/Soft Cap",
+            location: "Friends",
+            activity: "Type",
+        }
+    }
+
+    fn to_json_object(&self, _options: &OutputOptions) -> Value {
+        let mut map = Map::new();
+
+        map.insert("location".to_string(), Value::String(self.location.clone()));
+        map.insert("name".to_string(), Value::String(self.name.clone()));
+        map.insert("type".to_string(), Value::String(self.access.clone()));
+        map.insert(
+            "region".to_string(),
+            self.region.clone().map_or(Value::Null, Value::String),
+        );
+        map.insert(
+            "user_count".to_string(),
+            Value::Number(self.user_count.into()),
+        );
+        map.insert(
+            "world_capacity".to_string(),
+            Value::Number(self.world_capacity.into()),
+        );
+        map.insert(
+            "friends".to_string(),
+            Value::Array(
+                self.friends
+                    .iter()
+                    .map(|f| Value::String(f.clone()))
+                    .collect(),
+            ),
+        );
+
+        Value::Object(map)
+    }
+}
@@ -0,0 +1,281 @@
+use super::fetcher;
+use crate::history::{HistoryStore, WorldSample};
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+/// Configuration for the watch action
+#[derive(Debug, Clone)]
+pub struct WorldWatchOptions {
+    pub interval: u64,
+    /// Player growth between two polls reported as a spike
+    pub spike: i64,
+    pub json: bool,
+}
+
+/// A notable change between two samples of a world
+#[derive(Debug, Clone, Serialize)]
+struct WorldChange {
+    timestamp: String,
+    event: &'static str,
+    world_id: String,
+    world_name: String,
+    previous: i64,
+    current: i64,
+}
+
+impl WorldChange {
+    /// Human-readable one-line description of the change
+    fn describe(&self) -> String {
+        match self.event {
+            "world-version" => format!(
+                "🆕 {} published version {} (was {})",
+                self.world_name, self.current, self.previous
+            ),
+            _ => format!(
+                "📈 {} players spiked: {} → {}",
+                self.world_name, self.previous, self.current
+            ),
+        }
+    }
+}
+
+/// Handle the Watch action: poll worlds, record samples and report changes
+pub async fn handle_watch_action(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    world_ids: &[String],
+    options: WorldWatchOptions,
+) -> Result<()> {
+    let store = HistoryStore::open()?;
+    let interval = Duration::from_secs(options.interval.max(1));
+
+    // Seed with stored samples so versions published while not watching are reported
+    let mut previous: HashMap<String, WorldSample> = HashMap::new();
+    for world_id in world_ids {
+        if let Some(sample) = store.latest_world_sample(world_id)? {
+            previous.insert(world_id.clone(), sample);
+        }
+    }
+
+    if !options.json {
+        eprintln!(
+            "👀 Watching {} world(s) every {}s. Press Ctrl+C to stop.",
+            world_ids.len(),
+            interval.as_secs()
+        );
+    }
+
+    loop {
+        for world_id in world_ids {
+            let world = match fetcher::fetch_world_by_id(api_config, world_id).await {
+                Ok(world) => world,
+                Err(e) => {
+                    eprintln!("Warning: Failed to poll world '{world_id}': {e}");
+                    continue;
+                }
+            };
+
+            let sample = WorldSample::from(&world);
+            if let Err(e) = store.record_world_sample(&sample) {
+                eprintln!("Warning: Failed to record world sample: {e}");
+            }
+
+            emit_sample(&sample, options.json)?;
+            if let Some(before) = previous.get(world_id) {
+                for change in detect_changes(before, &sample, &options, interval) {
+                    emit_change(&change, options.json)?;
+                }
+            }
+            previous.insert(world_id.clone(), sample);
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(interval) => {}
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    Ok(())
+}
+
+/// Compare two samples of the same world
+fn detect_changes(
+    before: &WorldSample,
+    after: &WorldSample,
+    options: &WorldWatchOptions,
+    interval: Duration,
+) -> Vec<WorldChange> {
+    let change = |event, previous, current| WorldChange {
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        event,
+        world_id: after.world_id.clone(),
+        world_name: after.world_name.clone(),
+        previous,
+        current,
+    };
+
+    let mut changes = Vec::new();
+    if after.version > before.version {
+        changes.push(change("world-version", before.version, after.version));
+    }
+
+    // A stored sample from a previous run is too old to compare player counts with
+    let recent = after.observed_at - before.observed_at <= 2 * interval.as_secs() as i64;
+    if let (true, Some(was), Some(now)) = (recent, before.occupants, after.occupants) {
+        if now - was >= options.spike.max(1) {
+            changes.push(change("world-spike", was, now));
+        }
+    }
+    changes
+}
+
+/// Print a sample either as a JSON line or as a short status line
+fn emit_sample(sample: &WorldSample, json: bool) -> Result<()> {
+    if json {
+        let mut value = serde_json::to_value(sample)?;
+        value["event"] = "world-sample".into();
+        println!("{}", serde_json::to_string(&value)?);
+    } else {
+        let timestamp = chrono::Utc::now().to_rfc3339();
+        let players = sample
+            .occupants
+            .map(|occupants| occupants.to_string())
+            .unwrap_or_else(|| "?".to_string());
+        let favorites = sample
+            .favorites
+            .map(|favorites| favorites.to_string())
+            .unwrap_or_else(|| "?".to_string());
+        println!(
+            "[{timestamp}] {}: {players} players, {} visits, {favorites} favorites (v{})",
+            sample.world_name, sample.visits, sample.version
+        );
+    }
+    Ok(())
+}
+
+/// Print a change either as a JSON line or as human-readable text
+fn emit_change(change: &WorldChange, json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string(change)?);
+    } else {
+        println!("[{}] {}", change.timestamp, change.describe());
+    }
+    Ok(())
+}
+
+/// Handle the Export action: write the recorded samples of a world as CSV
+pub fn handle_export_action(
+    world_id: &str,
+    since_hours: Option<u64>,
+    output: Option<&Path>,
+) -> Result<()> {
+    let since = since_hours.map(|hours| chrono::Utc::now().timestamp() - hours as i64 * 3600);
+    let samples = HistoryStore::open()?.world_samples(world_id, since)?;
+    if samples.is_empty() {
+        return Err(anyhow::anyhow!(
+            "No samples recorded for world '{}'. Run 'vrcli worlds watch {}' first.",
+            world_id,
+            world_id
+        ));
+    }
+
+    let mut csv = String::from(WorldSample::CSV_HEADER);
+    csv.push('\n');
+    for sample in &samples {
+        csv.push_str(&sample.to_csv_row());
+        csv.push('\n');
+    }
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, csv)?;
+            eprintln!("Wrote {} sample(s) to {}", samples.len(), path.display());
+        }
+        None => std::io::stdout().write_all(csv.as_bytes())?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INTERVAL: Duration = Duration::from_secs(60);
+
+    /// Events detected between two samples with the given spike threshold
+    fn events(before: &WorldSample, after: &WorldSample, spike: i64) -> Vec<&'static str> {
+        let options = WorldWatchOptions {
+            interval: INTERVAL.as_secs(),
+            spike,
+            json: false,
+        };
+        detect_changes(before, after, &options, INTERVAL)
+            .iter()
+            .map(|change| change.event)
+            .collect()
+    }
+
+    #[test]
+    fn test_detect_version_change() {
+        let before = WorldSample::fixture(0, Some(5));
+        let after = WorldSample {
+            version: 4,
+            ..WorldSample::fixture(60, Some(5))
+        };
+        let options = WorldWatchOptions {
+            interval: INTERVAL.as_secs(),
+            spike: 10,
+            json: false,
+        };
+        let changes = detect_changes(&before, &after, &options, INTERVAL);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].event, "world-version");
+        assert_eq!((changes[0].previous, changes[0].current), (3, 4));
+
+        assert!(events(&before, &WorldSample::fixture(60, Some(5)), 10).is_empty());
+    }
+
+    #[test]
+    fn test_detect_spike_threshold() {
+        let before = WorldSample::fixture(0, Some(5));
+        assert_eq!(
+            events(&before, &WorldSample::fixture(60, Some(15)), 10),
+            vec!["world-spike"]
+        );
+        assert!(events(&before, &WorldSample::fixture(60, Some(14)), 10).is_empty());
+        // Drops and unknown player counts are never spikes
+        assert!(events(
+            &WorldSample::fixture(0, Some(50)),
+            &WorldSample::fixture(60, Some(5)),
+            10
+        )
+        .is_empty());
+        assert!(events(
+            &WorldSample::fixture(0, None),
+            &WorldSample::fixture(60, Some(50)),
+            10
+        )
+        .is_empty());
+        // A threshold below one still needs the count to grow
+        assert!(events(&before, &WorldSample::fixture(60, Some(5)), 0).is_empty());
+    }
+
+    #[test]
+    fn test_stale_sample_skips_spike_but_not_version() {
+        let before = WorldSample::fixture(0, Some(5));
+        // A sample from a previous run, long before this poll
+        let later = WorldSample {
+            version: 4,
+            ..WorldSample::fixture(3600, Some(50))
+        };
+        assert_eq!(events(&before, &later, 10), vec!["world-version"]);
+        // Two intervals apart still counts as recent
+        assert_eq!(
+            events(&before, &WorldSample::fixture(120, Some(50)), 10),
+            vec!["world-spike"]
+        );
+    }
+}
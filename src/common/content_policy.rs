@@ -0,0 +1,235 @@
+use super::location::{self, Location};
+use super::world_tags::TagFilter;
+use anyhow::Result;
+use futures::stream::{self, StreamExt};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use vrchatapi::apis;
+
+/// Worlds looked up at once when checking friend locations
+const WORLD_LOOKUP_CONCURRENCY: usize = 5;
+
+/// Location shown for friends in a world hidden by the content policy
+pub const HIDDEN_LOCATION: &str = "hidden (content policy)";
+
+/// The content policy from the config, applied to world listings and friend locations
+#[derive(Debug, Clone, Default)]
+pub struct ContentFilter {
+    /// `None` when nothing is hidden
+    filter: Option<TagFilter>,
+    /// Whether each world looked up so far is hidden; a watch reuses them across polls
+    hidden_by_world: Arc<Mutex<HashMap<String, bool>>>,
+}
+
+impl ContentFilter {
+    /// Load the policy from the config; `show_all` turns it off for this run
+    /// Fails instead of showing everything when the config or the policy cannot be read
+    pub fn load(show_all: bool) -> Result<Self> {
+        if show_all {
+            return Ok(Self::default());
+        }
+
+        let config = crate::config::Config::load()?;
+        if config.content_policy.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let filter = TagFilter::new(&[], &config.content_policy.hide).map_err(|e| {
+            anyhow::anyhow!("Invalid content policy in the config: {e}. Fix it or use --show-all")
+        })?;
+        Ok(Self {
+            filter: Some(filter),
+            ..Self::default()
+        })
+    }
+
+    /// Whether the policy hides anything at all
+    pub fn is_active(&self) -> bool {
+        self.filter.is_some()
+    }
+
+    /// Whether a world with these tags may be shown
+    pub fn allows(&self, tags: &[String]) -> bool {
+        self.filter
+            .as_ref()
+            .is_none_or(|filter| filter.matches(tags))
+    }
+
+    /// Fail for a single world the policy hides, e.g. one requested by ID
+    pub fn ensure_allowed(&self, world: &vrchatapi::models::World) -> Result<()> {
+        if self.allows(&world.tags) {
+            return Ok(());
+        }
+        Err(anyhow::anyhow!(
+            "World '{}' is hidden by the content policy (use --show-all to show it)",
+            world.id
+        ))
+    }
+
+    /// Drop the items whose world the policy hides, returning how many were dropped
+    pub fn retain<T>(&self, items: &mut Vec<T>, tags: impl Fn(&T) -> &[String]) -> usize {
+        let before = items.len();
+        items.retain(|item| self.allows(tags(item)));
+        before - items.len()
+    }
+
+    /// Look up which of the worlds the policy hides
+    /// Worlds that cannot be fetched count as hidden since their tags are unknown; they are
+    /// not remembered, so the next lookup tries again
+    pub async fn hidden_worlds(
+        &self,
+        api_config: &vrchatapi::apis::configuration::Configuration,
+        world_ids: HashSet<String>,
+    ) -> HashSet<String> {
+        if !self.is_active() {
+            return HashSet::new();
+        }
+
+        let (known, unknown): (Vec<String>, Vec<String>) = {
+            let hidden_by_world = self
+                .hidden_by_world
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            world_ids
+                .into_iter()
+                .partition(|world_id| hidden_by_world.contains_key(world_id))
+        };
+
+        let fetched: Vec<(String, Option<bool>)> = stream::iter(unknown)
+            .map(|world_id| async move {
+                let hidden = apis::worlds_api::get_world(api_config, &world_id)
+                    .await
+                    .ok()
+                    .map(|world| !self.allows(&world.tags));
+                (world_id, hidden)
+            })
+            .buffer_unordered(WORLD_LOOKUP_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut hidden_by_world = self
+            .hidden_by_world
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let mut hidden: HashSet<String> = known
+            .into_iter()
+            .filter(|world_id| hidden_by_world.get(world_id) == Some(&true))
+            .collect();
+        for (world_id, verdict) in fetched {
+            if let Some(verdict) = verdict {
+                hidden_by_world.insert(world_id.clone(), verdict);
+            }
+            if verdict != Some(false) {
+                hidden.insert(world_id);
+            }
+        }
+        hidden
+    }
+
+    /// Replace the locations in hidden worlds, returning how many were hidden
+    /// `location` picks the location string out of each item, e.g. a friend or history entry
+    pub async fn mask_locations<T>(
+        &self,
+        api_config: &vrchatapi::apis::configuration::Configuration,
+        items: &mut [T],
+        location: impl Fn(&mut T) -> &mut String,
+    ) -> usize {
+        if !self.is_active() {
+            return 0;
+        }
+
+        let world_ids: HashSet<String> = items
+            .iter_mut()
+            .filter_map(|item| instance_world_id(location(item)))
+            .collect();
+        let hidden_worlds = self.hidden_worlds(api_config, world_ids).await;
+
+        let mut hidden = 0;
+        for item in items.iter_mut() {
+            let location = location(item);
+            if instance_world_id(location).is_some_and(|id| hidden_worlds.contains(&id)) {
+                *location = HIDDEN_LOCATION.to_string();
+                hidden += 1;
+            }
+        }
+        hidden
+    }
+
+    /// Replace the location of a user in a hidden world, returning whether it was hidden
+    pub async fn mask_user_location(
+        &self,
+        api_config: &vrchatapi::apis::configuration::Configuration,
+        user: &mut vrchatapi::models::User,
+    ) -> bool {
+        if !self.is_active() {
+            return false;
+        }
+
+        let world_ids: HashSet<String> = [
+            user.location.as_deref(),
+            user.traveling_to_location.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .filter_map(instance_world_id)
+        .chain(user.world_id.clone())
+        .chain(user.traveling_to_world.clone())
+        .filter(|world_id| world_id.starts_with("wrld_"))
+        .collect();
+        if self.hidden_worlds(api_config, world_ids).await.is_empty() {
+            return false;
+        }
+
+        user.location = Some(HIDDEN_LOCATION.to_string());
+        user.world_id = None;
+        user.instance_id = None;
+        user.traveling_to_location = None;
+        user.traveling_to_world = None;
+        user.traveling_to_instance = None;
+        true
+    }
+}
+
+/// The world ID of an instance location; `None` for offline, private and traveling
+fn instance_world_id(location: &str) -> Option<String> {
+    match location::parse_location(location) {
+        Ok(Location::Instance(instance)) => Some(instance.world_id),
+        _ => None,
+    }
+}
+
+/// Tell how many entries the content policy hid; kept off stdout for JSON output
+pub fn print_hidden_footer(hidden: usize, what: &str, json: bool) {
+    if hidden == 0 {
+        return;
+    }
+    let message =
+        format!("{hidden} {what} hidden by the content policy (use --show-all to show them)");
+    if json {
+        eprintln!("Note: {message}");
+    } else {
+        println!("\n{message}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_filter_retain() {
+        let filter = ContentFilter {
+            filter: Some(TagFilter::new(&[], &["adult".to_string()]).unwrap()),
+            ..ContentFilter::default()
+        };
+        let mut worlds = vec![
+            vec!["author_tag_game".to_string()],
+            vec!["content_adult".to_string()],
+        ];
+        assert_eq!(filter.retain(&mut worlds, |tags| tags), 1);
+        assert_eq!(worlds, vec![vec!["author_tag_game".to_string()]]);
+
+        let mut worlds = vec![vec!["content_adult".to_string()]];
+        assert_eq!(ContentFilter::default().retain(&mut worlds, |tags| tags), 0);
+    }
+}
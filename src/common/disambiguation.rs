@@ -0,0 +1,164 @@
+use crate::config::Config;
+use anyhow::Result;
+use inquire::Select;
+use std::fmt;
+use std::io::IsTerminal;
+use vrchatapi::apis;
+
+/// Maximum number of characters of a bio shown in the prompt
+const BIO_SNIPPET_LENGTH: usize = 40;
+
+/// A user offered in the disambiguation prompt
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserCandidate {
+    pub id: String,
+    pub display_name: String,
+    pub status: String,
+    pub bio: String,
+}
+
+impl UserCandidate {
+    pub fn from_search(user: &vrchatapi::models::LimitedUserSearch) -> Self {
+        Self {
+            id: user.id.clone(),
+            display_name: user.display_name.clone(),
+            status: crate::common::utils::format_user_status(&user.status, false),
+            bio: user.bio.clone().unwrap_or_default(),
+        }
+    }
+
+    pub fn from_user(user: &vrchatapi::models::User) -> Self {
+        Self {
+            id: user.id.clone(),
+            display_name: user.display_name.clone(),
+            status: crate::common::utils::format_user_status(&user.status, false),
+            bio: user.bio.clone(),
+        }
+    }
+}
+
+impl fmt::Display for UserCandidate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.display_name, self.id)?;
+        if !self.status.is_empty() {
+            write!(f, " - {}", self.status)?;
+        }
+        let bio = bio_snippet(&self.bio, BIO_SNIPPET_LENGTH);
+        if !bio.is_empty() {
+            write!(f, " - {bio}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Shorten a bio to a single line of at most `max_chars` characters
+pub fn bio_snippet(bio: &str, max_chars: usize) -> String {
+    let single_line = bio.split_whitespace().collect::<Vec<_>>().join(" ");
+    if single_line.chars().count() <= max_chars {
+        single_line
+    } else {
+        let truncated: String = single_line
+            .chars()
+            .take(max_chars.saturating_sub(3))
+            .collect();
+        format!("{}...", truncated.trim_end())
+    }
+}
+
+/// Whether a user can answer a prompt
+pub fn is_interactive() -> bool {
+    std::io::stdin().is_terminal() && std::io::stderr().is_terminal()
+}
+
+/// Fetch status and bio for friends matched from the cache, falling back to name and ID only
+pub async fn candidates_from_ids(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    users: &[(String, String)],
+) -> Vec<UserCandidate> {
+    let mut candidates = Vec::with_capacity(users.len());
+    for (user_id, display_name) in users {
+        let candidate = match apis::users_api::get_user(api_config, user_id).await {
+            Ok(user) => UserCandidate::from_user(&user),
+            Err(_) => UserCandidate {
+                id: user_id.clone(),
+                display_name: display_name.clone(),
+                status: String::new(),
+                bio: String::new(),
+            },
+        };
+        candidates.push(candidate);
+    }
+    candidates
+}
+
+/// Ask the user to pick one of the candidates for an ambiguous display name.
+/// The choice is remembered so the same name resolves directly next time.
+pub fn choose_user(query: &str, candidates: Vec<UserCandidate>) -> Result<String> {
+    let chosen = Select::new(
+        &format!("Which user did you mean by '{query}'?"),
+        candidates,
+    )
+    .with_page_size(10)
+    .prompt()?;
+
+    remember_choice(query, &chosen.id);
+    Ok(chosen.id)
+}
+
+/// Store the user picked for a display name, warning instead of failing
+fn remember_choice(name: &str, user_id: &str) {
+    let result = Config::load().and_then(|mut config| {
+        config.remember_name(name, user_id);
+        config.save()
+    });
+
+    match result {
+        Ok(()) => eprintln!(
+            "Note: '{name}' will resolve to {user_id} from now on ('vrcli alias forget \"{name}\"' undoes this)"
+        ),
+        Err(e) => eprintln!("Warning: Failed to remember the choice for '{name}': {e}"),
+    }
+}
+
+/// Look up a remembered choice without failing when no config is present
+pub fn lookup_remembered_name(name: &str) -> Option<String> {
+    let user_id = Config::load()
+        .ok()
+        .and_then(|config| config.remembered_name(name).map(str::to_string))?;
+    eprintln!("Note: Using your earlier choice {user_id} for '{name}'");
+    Some(user_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bio_snippet() {
+        assert_eq!(bio_snippet("short bio", 40), "short bio");
+        assert_eq!(bio_snippet("line one\n\nline two", 40), "line one line two");
+        assert_eq!(bio_snippet("abcdefghij", 8), "abcde...");
+        assert_eq!(bio_snippet("", 8), "");
+    }
+
+    #[test]
+    fn test_candidate_display() {
+        let candidate = UserCandidate {
+            id: "usr_a".to_string(),
+            display_name: "Alice".to_string(),
+            status: "Active".to_string(),
+            bio: "Hello there".to_string(),
+        };
+        assert_eq!(
+            candidate.to_string(),
+            "Alice (usr_a) - Active - Hello there"
+        );
+
+        let bare = UserCandidate {
+            status: String::new(),
+            bio: String::new(),
+            ..candidate
+        };
+        assert_eq!(bare.to_string(), "Alice (usr_a)");
+    }
+}
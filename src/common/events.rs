@@ -0,0 +1,391 @@
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Kind of change observed while watching friends and notifications
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    FriendOnline,
+    FriendOffline,
+    FriendLocation,
+    FriendStatus,
+    Notification(NotificationKind),
+}
+
+/// Notification types that can be turned into events
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationKind {
+    FriendRequest,
+    Invite,
+    InviteResponse,
+    Message,
+    RequestInvite,
+    RequestInviteResponse,
+    VoteToKick,
+}
+
+impl EventKind {
+    /// Event name used in output and hook configuration
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::FriendOnline => "friend-online",
+            Self::FriendOffline => "friend-offline",
+            Self::FriendLocation => "friend-location",
+            Self::FriendStatus => "friend-status",
+            Self::Notification(kind) => match kind {
+                NotificationKind::FriendRequest => "notification-friend-request",
+                NotificationKind::Invite => "notification-invite",
+                NotificationKind::InviteResponse => "notification-invite-response",
+                NotificationKind::Message => "notification-message",
+                NotificationKind::RequestInvite => "notification-request-invite",
+                NotificationKind::RequestInviteResponse => "notification-request-invite-response",
+                NotificationKind::VoteToKick => "notification-votetokick",
+            },
+        }
+    }
+
+    /// Get all available event names
+    pub fn all_names() -> &'static [&'static str] {
+        &[
+            "friend-online",
+            "friend-offline",
+            "friend-location",
+            "friend-status",
+            "notification-friend-request",
+            "notification-invite",
+            "notification-invite-response",
+            "notification-message",
+            "notification-request-invite",
+            "notification-request-invite-response",
+            "notification-votetokick",
+        ]
+    }
+}
+
+impl From<&vrchatapi::models::NotificationType> for NotificationKind {
+    fn from(notification_type: &vrchatapi::models::NotificationType) -> Self {
+        use vrchatapi::models::NotificationType;
+        match notification_type {
+            NotificationType::FriendRequest => Self::FriendRequest,
+            NotificationType::Invite => Self::Invite,
+            NotificationType::InviteResponse => Self::InviteResponse,
+            NotificationType::Message => Self::Message,
+            NotificationType::RequestInvite => Self::RequestInvite,
+            NotificationType::RequestInviteResponse => Self::RequestInviteResponse,
+            NotificationType::Votetokick => Self::VoteToKick,
+        }
+    }
+}
+
+/// A single event emitted by the watch stream
+#[derive(Debug, Clone, Serialize)]
+pub struct Event {
+    pub event: String,
+    pub timestamp: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub world_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip)]
+    pub kind: Option<EventKind>,
+}
+
+impl Event {
+    fn new(kind: EventKind, timestamp: &str) -> Self {
+        Self {
+            event: kind.as_str().to_string(),
+            timestamp: timestamp.to_string(),
+            user_id: None,
+            display_name: None,
+            status: None,
+            previous_status: None,
+            platform: None,
+            location: None,
+            previous_location: None,
+            world_id: None,
+            notification_id: None,
+            message: None,
+            kind: Some(kind),
+        }
+    }
+
+    /// Build a friend event from the friend's current state
+    fn from_friend(
+        kind: EventKind,
+        friend: &vrchatapi::models::LimitedUserFriend,
+        timestamp: &str,
+    ) -> Self {
+        let mut event = Self::new(kind, timestamp);
+        event.user_id = Some(friend.id.clone());
+        event.display_name = Some(friend.display_name.clone());
+        event.status = Some(crate::common::utils::format_user_status(
+            &friend.status,
+            false,
+        ));
+        event.platform = Some(friend.last_platform.clone());
+        event.location = Some(friend.location.clone());
+        event.world_id = world_id_of(&friend.location);
+        event
+    }
+
+    /// Build an event from a notification
+    pub fn from_notification(
+        notification: &vrchatapi::models::Notification,
+        timestamp: &str,
+    ) -> Self {
+        let kind = EventKind::Notification(NotificationKind::from(&notification.r#type));
+        let mut event = Self::new(kind, timestamp);
+        event.user_id = Some(notification.sender_user_id.clone());
+        event.display_name = notification.sender_username.clone();
+        event.notification_id = Some(notification.id.clone());
+        if !notification.message.is_empty() {
+            event.message = Some(notification.message.clone());
+        }
+
+        // Invite details carry the target location as a JSON encoded string
+        if let Ok(details) = serde_json::from_str::<serde_json::Value>(&notification.details) {
+            if let Some(location) = details
+                .get("worldId")
+                .or_else(|| details.get("instanceId"))
+                .and_then(|v| v.as_str())
+            {
+                event.location = Some(location.to_string());
+                event.world_id = world_id_of(location);
+            }
+        }
+
+        event
+    }
+
+    /// Flatten the event into (name, value) pairs for templating and env vars
+    pub fn fields(&self) -> Vec<(&'static str, String)> {
+        let mut fields = vec![
+            ("event", self.event.clone()),
+            ("timestamp", self.timestamp.clone()),
+        ];
+
+        let optional = [
+            ("user_id", &self.user_id),
+            ("display_name", &self.display_name),
+            ("status", &self.status),
+            ("previous_status", &self.previous_status),
+            ("platform", &self.platform),
+            ("location", &self.location),
+            ("previous_location", &self.previous_location),
+            ("world_id", &self.world_id),
+            ("notification_id", &self.notification_id),
+            ("message", &self.message),
+        ];
+
+        for (name, value) in optional {
+            fields.push((name, value.clone().unwrap_or_default()));
+        }
+
+        fields
+    }
+
+    /// Human-readable one-line description of the event
+    pub fn describe(&self) -> String {
+        let who = self
+            .display_name
+            .as_deref()
+            .or(self.user_id.as_deref())
+            .unwrap_or("Unknown");
+        let location = self.location.as_deref().unwrap_or("unknown");
+
+        match self.kind {
+            Some(EventKind::FriendOnline) => format!("🟢 {who} came online ({location})"),
+            Some(EventKind::FriendOffline) => format!("⚫ {who} went offline"),
+            Some(EventKind::FriendLocation) => format!("📍 {who} moved to {location}"),
+            Some(EventKind::FriendStatus) => format!(
+                "💬 {who} changed status: {} → {}",
+                self.previous_status.as_deref().unwrap_or("?"),
+                self.status.as_deref().unwrap_or("?")
+            ),
+            Some(EventKind::Notification(_)) | None => {
+                let message = self.message.as_deref().unwrap_or("");
+                format!("🔔 {} from {who} {message}", self.event)
+                    .trim_end()
+                    .to_string()
+            }
+        }
+    }
+}
+
+/// Extract the world ID from a location string (e.g. `wrld_xxx:12345~private(usr_xxx)`)
+pub fn world_id_of(location: &str) -> Option<String> {
+    location
+        .split(':')
+        .next()
+        .filter(|world| world.starts_with("wrld_"))
+        .map(|world| world.to_string())
+}
+
+/// Compare two snapshots of online friends and produce events for every change
+pub fn diff_friends(
+    previous: &HashMap<String, vrchatapi::models::LimitedUserFriend>,
+    current: &HashMap<String, vrchatapi::models::LimitedUserFriend>,
+    timestamp: &str,
+) -> Vec<Event> {
+    let mut events = Vec::new();
+
+    for (id, friend) in current {
+        match previous.get(id) {
+            None => events.push(Event::from_friend(
+                EventKind::FriendOnline,
+                friend,
+                timestamp,
+            )),
+            Some(before) => {
+                if before.location != friend.location {
+                    let mut event =
+                        Event::from_friend(EventKind::FriendLocation, friend, timestamp);
+                    event.previous_location = Some(before.location.clone());
+                    events.push(event);
+                }
+                if before.status != friend.status {
+                    let mut event = Event::from_friend(EventKind::FriendStatus, friend, timestamp);
+                    event.previous_status = Some(crate::common::utils::format_user_status(
+                        &before.status,
+                        false,
+                    ));
+                    events.push(event);
+                }
+            }
+        }
+    }
+
+    for (id, friend) in previous {
+        if !current.contains_key(id) {
+            let mut event = Event::from_friend(EventKind::FriendOffline, friend, timestamp);
+            event.location = None;
+            event.world_id = None;
+            event.previous_location = Some(friend.location.clone());
+            events.push(event);
+        }
+    }
+
+    // Keep output stable between runs
+    events.sort_by(|a, b| {
+        a.display_name
+            .cmp(&b.display_name)
+            .then_with(|| a.event.cmp(&b.event))
+    });
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vrchatapi::models::{LimitedUserFriend, UserStatus};
+
+    fn friend(id: &str, name: &str, location: &str, status: UserStatus) -> LimitedUserFriend {
+        LimitedUserFriend {
+            id: id.to_string(),
+            display_name: name.to_string(),
+            location: location.to_string(),
+            status,
+            ..Default::default()
+        }
+    }
+
+    fn snapshot(friends: Vec<LimitedUserFriend>) -> HashMap<String, LimitedUserFriend> {
+        friends.into_iter().map(|f| (f.id.clone(), f)).collect()
+    }
+
+    #[test]
+    fn test_world_id_of() {
+        assert_eq!(
+            world_id_of("wrld_abc:12345~friends(usr_x)"),
+            Some("wrld_abc".to_string())
+        );
+        assert_eq!(world_id_of("private"), None);
+        assert_eq!(world_id_of("offline"), None);
+    }
+
+    #[test]
+    fn test_diff_online_and_offline() {
+        let previous = snapshot(vec![friend(
+            "usr_a",
+            "Alice",
+            "private",
+            UserStatus::Active,
+        )]);
+        let current = snapshot(vec![friend("usr_b", "Bob", "wrld_x:1", UserStatus::JoinMe)]);
+
+        let events = diff_friends(&previous, &current, "t");
+        let names: Vec<&str> = events.iter().map(|e| e.event.as_str()).collect();
+        assert_eq!(names, vec!["friend-offline", "friend-online"]);
+        assert_eq!(events[1].world_id.as_deref(), Some("wrld_x"));
+        assert_eq!(events[0].previous_location.as_deref(), Some("private"));
+    }
+
+    #[test]
+    fn test_diff_location_and_status() {
+        let previous = snapshot(vec![friend(
+            "usr_a",
+            "Alice",
+            "wrld_x:1",
+            UserStatus::Active,
+        )]);
+        let current = snapshot(vec![friend("usr_a", "Alice", "wrld_y:2", UserStatus::Busy)]);
+
+        let events = diff_friends(&previous, &current, "t");
+        assert_eq!(events.len(), 2);
+        let location = events
+            .iter()
+            .find(|e| e.event == "friend-location")
+            .unwrap();
+        assert_eq!(location.previous_location.as_deref(), Some("wrld_x:1"));
+        assert_eq!(location.world_id.as_deref(), Some("wrld_y"));
+        let status = events.iter().find(|e| e.event == "friend-status").unwrap();
+        assert_eq!(status.previous_status.as_deref(), Some("Active"));
+        assert_eq!(status.status.as_deref(), Some("Busy"));
+    }
+
+    #[test]
+    fn test_diff_no_changes() {
+        let previous = snapshot(vec![friend(
+            "usr_a",
+            "Alice",
+            "wrld_x:1",
+            UserStatus::Active,
+        )]);
+        assert!(diff_friends(&previous, &previous.clone(), "t").is_empty());
+    }
+
+    #[test]
+    fn test_event_from_notification() {
+        let mut notification = vrchatapi::models::Notification::new(
+            "2024-01-01T00:00:00Z".to_string(),
+            r#"{"worldId":"wrld_abc:123~hidden(usr_x)","worldName":"Test"}"#.to_string(),
+            "not_1".to_string(),
+            "Join me".to_string(),
+            "usr_sender".to_string(),
+            vrchatapi::models::NotificationType::Invite,
+        );
+        notification.sender_username = Some("Sender".to_string());
+
+        let event = Event::from_notification(&notification, "t");
+        assert_eq!(event.event, "notification-invite");
+        assert_eq!(event.world_id.as_deref(), Some("wrld_abc"));
+        assert_eq!(event.notification_id.as_deref(), Some("not_1"));
+        assert_eq!(event.display_name.as_deref(), Some("Sender"));
+    }
+}
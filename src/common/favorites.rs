@@ -0,0 +1,72 @@
+use anyhow::Result;
+use vrchatapi::apis::favorites_api;
+use vrchatapi::models::{FavoriteGroup, FavoriteType};
+
+/// Page size used when listing favorites
+const FAVORITES_PAGE_SIZE: i32 = 100;
+
+/// Find one of our favorite groups of a type by its name or display name (case-insensitive)
+pub async fn find_favorite_group(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    favorite_type: FavoriteType,
+    name: &str,
+) -> Result<FavoriteGroup> {
+    let groups = favorites_api::get_favorite_groups(api_config, Some(100), None, None, None)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch favorite groups: {}", e))?;
+
+    let groups: Vec<FavoriteGroup> = groups
+        .into_iter()
+        .filter(|group| group.r#type == favorite_type)
+        .collect();
+
+    let wanted = name.to_lowercase();
+    groups
+        .iter()
+        .find(|group| {
+            group.name.to_lowercase() == wanted || group.display_name.to_lowercase() == wanted
+        })
+        .cloned()
+        .ok_or_else(|| {
+            let available: Vec<&str> = groups.iter().map(|g| g.display_name.as_str()).collect();
+            anyhow::anyhow!(
+                "No {} favorite group named '{}'. Available groups: {}",
+                favorite_type,
+                name,
+                available.join(", ")
+            )
+        })
+}
+
+/// Fetch the IDs of favorited users or worlds, optionally limited to one group tag
+pub async fn fetch_favorite_ids(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    favorite_type: FavoriteType,
+    group_tag: Option<&str>,
+) -> Result<Vec<String>> {
+    let type_name = favorite_type.to_string();
+    let mut ids = Vec::new();
+    let mut offset = 0;
+
+    loop {
+        let page = favorites_api::get_favorites(
+            api_config,
+            Some(FAVORITES_PAGE_SIZE),
+            Some(offset),
+            Some(&type_name),
+            group_tag,
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch favorites: {}", e))?;
+
+        let page_len = page.len() as i32;
+        ids.extend(page.into_iter().map(|favorite| favorite.favorite_id));
+
+        if page_len < FAVORITES_PAGE_SIZE {
+            break;
+        }
+        offset += page_len;
+    }
+
+    Ok(ids)
+}
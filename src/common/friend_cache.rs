@@ -0,0 +1,128 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// How long a cached friend list is considered fresh
+const CACHE_TTL_SECONDS: i64 = 15 * 60;
+
+/// Minimal friend information needed for name resolution
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CachedFriend {
+    pub id: String,
+    pub display_name: String,
+}
+
+/// Friend list stored on disk between runs
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FriendCache {
+    pub fetched_at: i64,
+    pub friends: Vec<CachedFriend>,
+}
+
+impl FriendCache {
+    /// Build a cache from a complete friend list
+    pub fn from_friends(friends: &[vrchatapi::models::LimitedUserFriend], fetched_at: i64) -> Self {
+        Self {
+            fetched_at,
+            friends: friends
+                .iter()
+                .map(|friend| CachedFriend {
+                    id: friend.id.clone(),
+                    display_name: friend.display_name.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Whether the cache is still fresh at the given time
+    pub fn is_fresh(&self, now: i64) -> bool {
+        now - self.fetched_at < CACHE_TTL_SECONDS
+    }
+
+    /// Load the cache from disk, if present and readable
+    pub fn load() -> Option<Self> {
+        let content = fs::read_to_string(get_cache_path().ok()?).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Save the cache to disk
+    pub fn save(&self) -> Result<()> {
+        let cache_path = get_cache_path()?;
+        if let Some(parent) = cache_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&cache_path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+}
+
+/// Store a complete friend list in the cache, warning instead of failing
+pub fn update_cache(friends: &[vrchatapi::models::LimitedUserFriend]) {
+    let cache = FriendCache::from_friends(friends, chrono::Utc::now().timestamp());
+    if let Err(e) = cache.save() {
+        eprintln!("Warning: Failed to update friend cache: {e}");
+    }
+}
+
+/// Get the friend list, from the cache when fresh or from the API otherwise
+pub async fn cached_friends(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+) -> Result<Vec<CachedFriend>> {
+    let now = chrono::Utc::now().timestamp();
+    if let Some(cache) = FriendCache::load() {
+        if cache.is_fresh(now) {
+            return Ok(cache.friends);
+        }
+    }
+
+    let friends =
+        crate::commands::friends::fetcher::fetch_all_friends_parallel(api_config, None).await?;
+    crate::history::record_observed_friends(&friends);
+
+    let cache = FriendCache::from_friends(&friends, now);
+    if let Err(e) = cache.save() {
+        eprintln!("Warning: Failed to update friend cache: {e}");
+    }
+    Ok(cache.friends)
+}
+
+fn get_cache_path() -> Result<PathBuf> {
+    let cache_dir = dirs::cache_dir().ok_or_else(|| anyhow!("Could not find cache directory"))?;
+
+    Ok(cache_dir.join("vrcli").join("friends.json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_freshness() {
+        let cache = FriendCache {
+            fetched_at: 1000,
+            friends: Vec::new(),
+        };
+        assert!(cache.is_fresh(1000));
+        assert!(cache.is_fresh(1000 + CACHE_TTL_SECONDS - 1));
+        assert!(!cache.is_fresh(1000 + CACHE_TTL_SECONDS));
+    }
+
+    #[test]
+    fn test_cache_from_friends() {
+        let friends = vec![vrchatapi::models::LimitedUserFriend {
+            id: "usr_a".to_string(),
+            display_name: "Alice".to_string(),
+            ..Default::default()
+        }];
+        let cache = FriendCache::from_friends(&friends, 42);
+        assert_eq!(cache.fetched_at, 42);
+        assert_eq!(
+            cache.friends,
+            vec![CachedFriend {
+                id: "usr_a".to_string(),
+                display_name: "Alice".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use super::location::{self, Location};
+use super::name_matching::normalize_name;
+use super::utils::{format_platform_short, format_user_status};
+use anyhow::Result;
+
+/// Filter for selecting friends, written as comma-separated terms:
+/// `status=joinme|active,platform=pc,world=wrld_...,name=neko,joinable`.
+/// Values of one key are alternatives; different keys must all match.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FriendFilter {
+    statuses: Vec<String>,
+    platforms: Vec<String>,
+    worlds: Vec<String>,
+    names: Vec<String>,
+    joinable: bool,
+}
+
+/// Fold a status or platform name for comparison ("Join me" -> "joinme")
+fn fold(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+impl FriendFilter {
+    /// Parse a filter expression; an empty expression or `all` matches everyone
+    pub fn parse(expression: &str) -> Result<Self> {
+        let mut filter = FriendFilter::default();
+
+        for term in expression.split(',').map(str::trim) {
+            if term.is_empty() || term == "all" {
+                continue;
+            }
+            if term == "joinable" {
+                filter.joinable = true;
+                continue;
+            }
+
+            let (key, value) = term.split_once('=').ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid filter term '{}'. Use key=value with status, platform, world or name, or 'joinable'",
+                    term
+                )
+            })?;
+            let values = value.split('|').map(str::trim).filter(|v| !v.is_empty());
+
+            match key.trim() {
+                "status" => filter.statuses.extend(values.map(fold)),
+                "platform" => filter.platforms.extend(values.map(fold)),
+                "world" => filter.worlds.extend(values.map(str::to_string)),
+                "name" => filter.names.extend(values.map(normalize_name)),
+                other => {
+                    return Err(anyhow::anyhow!(
+                    "Unknown filter key '{}'. Valid keys: status, platform, world, name, joinable",
+                    other
+                ))
+                }
+            }
+        }
+
+        Ok(filter)
+    }
+
+    /// Whether a friend matches every term of the filter
+    pub fn matches(&self, friend: &vrchatapi::models::LimitedUserFriend) -> bool {
+        if !self.statuses.is_empty()
+            && !self
+                .statuses
+                .contains(&fold(&format_user_status(&friend.status, false)))
+        {
+            return false;
+        }
+
+        if !self.platforms.is_empty() {
+            let short = fold(&format_platform_short(&friend.last_platform));
+            let raw = fold(&friend.last_platform);
+            if !self.platforms.iter().any(|p| *p == short || *p == raw) {
+                return false;
+            }
+        }
+
+        let instance = match location::parse_location(&friend.location) {
+            Ok(Location::Instance(instance)) => Some(instance),
+            _ => None,
+        };
+
+        if !self.worlds.is_empty()
+            && !instance
+                .as_ref()
+                .is_some_and(|i| self.worlds.contains(&i.world_id))
+        {
+            return false;
+        }
+
+        if self.joinable && !instance.is_some_and(|i| i.access.is_joinable()) {
+            return false;
+        }
+
+        if !self.names.is_empty() {
+            let name = normalize_name(&friend.display_name);
+            if !self.names.iter().any(|n| name.contains(n.as_str())) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vrchatapi::models::{LimitedUserFriend, UserStatus};
+
+    #[test]
+    fn test_parse_filter() {
+        assert_eq!(FriendFilter::parse("").unwrap(), FriendFilter::default());
+        assert_eq!(FriendFilter::parse("all").unwrap(), FriendFilter::default());
+        assert!(FriendFilter::parse("status").is_err());
+        assert!(FriendFilter::parse("colour=red").is_err());
+
+        let filter = FriendFilter::parse("status=Join Me|active, platform=pc, joinable").unwrap();
+        assert_eq!(filter.statuses, vec!["joinme", "active"]);
+        assert_eq!(filter.platforms, vec!["pc"]);
+        assert!(filter.joinable);
+    }
+
+    #[test]
+    fn test_filter_matches() {
+        let pc_join = LimitedUserFriend {
+            display_name: "Neko".to_string(),
+            status: UserStatus::JoinMe,
+            last_platform: "standalonewindows".to_string(),
+            location: "wrld_a:1~hidden(usr_x)".to_string(),
+            ..Default::default()
+        };
+        let quest_busy = LimitedUserFriend {
+            display_name: "Inu".to_string(),
+            status: UserStatus::Busy,
+            last_platform: "android".to_string(),
+            location: "wrld_b:2~private(usr_y)".to_string(),
+            ..Default::default()
+        };
+
+        let filter = FriendFilter::parse("status=joinme,platform=pc").unwrap();
+        assert!(filter.matches(&pc_join));
+        assert!(!filter.matches(&quest_busy));
+
+        let filter = FriendFilter::parse("joinable").unwrap();
+        assert!(filter.matches(&pc_join));
+        assert!(!filter.matches(&quest_busy));
+
+        let filter = FriendFilter::parse("world=wrld_b,name=in").unwrap();
+        assert!(!filter.matches(&pc_join));
+        assert!(filter.matches(&quest_busy));
+
+        assert!(FriendFilter::default().matches(&quest_busy));
+    }
+}
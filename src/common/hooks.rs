@@ -0,0 +1,162 @@
+use super::events::Event;
+use crate::config::HookConfig;
+use anyhow::Result;
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
+
+/// Check whether a hook should fire for the given event
+pub fn hook_matches(hook: &HookConfig, event: &Event) -> bool {
+    if hook.event != event.event {
+        return false;
+    }
+
+    if !hook.users.is_empty() {
+        let matches_user = hook.users.iter().any(|user| {
+            event.user_id.as_deref() == Some(user.as_str())
+                || event
+                    .display_name
+                    .as_deref()
+                    .is_some_and(|name| name.eq_ignore_ascii_case(user))
+        });
+        if !matches_user {
+            return false;
+        }
+    }
+
+    if let Some(world) = &hook.world {
+        if event.world_id.as_deref() != Some(world.as_str()) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Replace `{field}` placeholders with values from the event
+pub fn expand_template(template: &str, event: &Event) -> String {
+    let mut result = template.to_string();
+    for (name, value) in event.fields() {
+        result = result.replace(&format!("{{{name}}}"), &value);
+    }
+    result
+}
+
+/// Run every configured hook that matches the event
+pub async fn run_matching_hooks(hooks: &[HookConfig], event: &Event) {
+    for hook in hooks.iter().filter(|hook| hook_matches(hook, event)) {
+        if let Err(e) = run_hook(hook, event).await {
+            eprintln!("Warning: Hook '{}' failed: {e}", hook.command);
+        }
+    }
+}
+
+/// Run a single hook with the event JSON on stdin and event fields as env vars
+async fn run_hook(hook: &HookConfig, event: &Event) -> Result<()> {
+    let (shell, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+
+    let mut command = tokio::process::Command::new(shell);
+    command
+        .arg(flag)
+        .arg(&hook.command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit());
+
+    for (name, value) in event.fields() {
+        command.env(format!("VRCLI_{}", name.to_uppercase()), value);
+    }
+    for (name, template) in &hook.env {
+        command.env(name, expand_template(template, event));
+    }
+
+    let mut child = command.spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        let payload = serde_json::to_vec(event)?;
+        // The hook may exit without reading stdin; that is not an error
+        let _ = stdin.write_all(&payload).await;
+    }
+
+    let status = child.wait().await?;
+    if !status.success() {
+        return Err(anyhow::anyhow!("exited with {}", status));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn event(name: &str, user_id: &str, display_name: &str, world_id: Option<&str>) -> Event {
+        let friend = vrchatapi::models::LimitedUserFriend {
+            id: user_id.to_string(),
+            display_name: display_name.to_string(),
+            location: world_id
+                .map(|w| format!("{w}:123"))
+                .unwrap_or_else(|| "private".to_string()),
+            ..Default::default()
+        };
+
+        let previous = std::collections::HashMap::new();
+        let current = std::collections::HashMap::from([(friend.id.clone(), friend)]);
+        let mut event = crate::common::events::diff_friends(&previous, &current, "t")
+            .pop()
+            .unwrap();
+        event.event = name.to_string();
+        event
+    }
+
+    fn hook(event: &str, users: &[&str], world: Option<&str>) -> HookConfig {
+        HookConfig {
+            event: event.to_string(),
+            command: "true".to_string(),
+            users: users.iter().map(|u| u.to_string()).collect(),
+            world: world.map(|w| w.to_string()),
+            env: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_hook_matches_event_name() {
+        let e = event("friend-online", "usr_a", "Alice", None);
+        assert!(hook_matches(&hook("friend-online", &[], None), &e));
+        assert!(!hook_matches(&hook("friend-offline", &[], None), &e));
+    }
+
+    #[test]
+    fn test_hook_matches_users() {
+        let e = event("friend-online", "usr_a", "Alice", None);
+        assert!(hook_matches(&hook("friend-online", &["usr_a"], None), &e));
+        assert!(hook_matches(&hook("friend-online", &["alice"], None), &e));
+        assert!(!hook_matches(&hook("friend-online", &["usr_b"], None), &e));
+    }
+
+    #[test]
+    fn test_hook_matches_world() {
+        let e = event("friend-location", "usr_a", "Alice", Some("wrld_x"));
+        assert!(hook_matches(
+            &hook("friend-location", &[], Some("wrld_x")),
+            &e
+        ));
+        assert!(!hook_matches(
+            &hook("friend-location", &[], Some("wrld_y")),
+            &e
+        ));
+    }
+
+    #[test]
+    fn test_expand_template() {
+        let e = event("friend-location", "usr_a", "Alice", Some("wrld_x"));
+        assert_eq!(
+            expand_template("{display_name} joined {world_id} ({unknown})", &e),
+            "Alice joined wrld_x ({unknown})"
+        );
+    }
+}
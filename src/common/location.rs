@@ -0,0 +1,318 @@
+use anyhow::Result;
+use std::fmt;
+
+/// A VRChat location string such as `wrld_...:12345~friends(usr_...)~region(eu)`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Location {
+    Offline,
+    /// The user is in a private world, so the location is hidden
+    Private,
+    /// The user is between instances
+    Traveling,
+    Instance(InstanceLocation),
+}
+
+/// Who can join an instance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstanceAccess {
+    Public,
+    FriendsPlus,
+    Friends,
+    InvitePlus,
+    Invite,
+    Group,
+    GroupPlus,
+    GroupPublic,
+}
+
+impl InstanceAccess {
+    /// Whether people other than invitees can join on their own
+    pub fn is_joinable(&self) -> bool {
+        !matches!(self, InstanceAccess::Invite | InstanceAccess::InvitePlus)
+    }
+
+    /// Parse an instance type as written on the command line (`friends+`, `invite`, ...)
+    pub fn from_type_name(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "public" => Ok(InstanceAccess::Public),
+            "friends+" | "friendsplus" | "hidden" => Ok(InstanceAccess::FriendsPlus),
+            "friends" => Ok(InstanceAccess::Friends),
+            "invite+" | "inviteplus" => Ok(InstanceAccess::InvitePlus),
+            "invite" | "private" => Ok(InstanceAccess::Invite),
+            "group" => Ok(InstanceAccess::Group),
+            "group+" | "groupplus" => Ok(InstanceAccess::GroupPlus),
+            "group-public" | "grouppublic" => Ok(InstanceAccess::GroupPublic),
+            _ => Err(anyhow::anyhow!(
+                "Unknown instance type '{}'. Valid types: public, friends+, friends, invite+, invite, group, group+, group-public",
+                name
+            )),
+        }
+    }
+
+    /// Whether the instance is owned by a group rather than a user
+    pub fn is_group(&self) -> bool {
+        matches!(
+            self,
+            InstanceAccess::Group | InstanceAccess::GroupPlus | InstanceAccess::GroupPublic
+        )
+    }
+}
+
+impl fmt::Display for InstanceAccess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            InstanceAccess::Public => "Public",
+            InstanceAccess::FriendsPlus => "Friends+",
+            InstanceAccess::Friends => "Friends",
+            InstanceAccess::InvitePlus => "Invite+",
+            InstanceAccess::Invite => "Invite",
+            InstanceAccess::Group => "Group",
+            InstanceAccess::GroupPlus => "Group+",
+            InstanceAccess::GroupPublic => "Group Public",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A parsed world instance location
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstanceLocation {
+    pub world_id: String,
+    /// Everything after the colon, including `~` modifiers
+    pub instance_id: String,
+    /// The instance name before any `~` modifiers
+    pub name: String,
+    pub access: InstanceAccess,
+    /// User or group that owns a non-public instance
+    pub owner_id: Option<String>,
+    pub region: Option<String>,
+}
+
+impl InstanceLocation {
+    /// The full location string (`world_id:instance_id`)
+    pub fn location(&self) -> String {
+        format!("{}:{}", self.world_id, self.instance_id)
+    }
+
+    /// URL that opens the VRChat client directly in this instance
+    pub fn launch_url(&self) -> String {
+        launch_uri(&self.world_id, Some(&self.instance_id))
+    }
+
+    /// vrchat.com page that offers to launch this instance
+    pub fn web_url(&self) -> String {
+        web_launch_url(&self.world_id, Some(&self.instance_id))
+    }
+}
+
+/// `vrchat://` URI for a world, or for one of its instances
+pub fn launch_uri(world_id: &str, instance_id: Option<&str>) -> String {
+    match instance_id {
+        Some(instance_id) => format!("vrchat://launch?ref=vrchat.com&id={world_id}:{instance_id}"),
+        None => format!("vrchat://launch?ref=vrchat.com&id={world_id}"),
+    }
+}
+
+/// vrchat.com launch page for a world, or for one of its instances
+pub fn web_launch_url(world_id: &str, instance_id: Option<&str>) -> String {
+    match instance_id {
+        Some(instance_id) => {
+            format!("https://vrchat.com/home/launch?worldId={world_id}&instanceId={instance_id}")
+        }
+        None => format!("https://vrchat.com/home/launch?worldId={world_id}"),
+    }
+}
+
+impl fmt::Display for InstanceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.location())
+    }
+}
+
+/// Parse a location string as returned by the API
+pub fn parse_location(location: &str) -> Result<Location> {
+    match location {
+        "" | "offline" => return Ok(Location::Offline),
+        "private" => return Ok(Location::Private),
+        "traveling" | "traveling:traveling" => return Ok(Location::Traveling),
+        _ => {}
+    }
+
+    let (world_id, instance_id) = location
+        .split_once(':')
+        .ok_or_else(|| anyhow::anyhow!("Invalid location '{}': missing instance", location))?;
+
+    if !world_id.starts_with("wrld_") {
+        return Err(anyhow::anyhow!(
+            "Invalid location '{}': world IDs start with 'wrld_'",
+            location
+        ));
+    }
+
+    let mut parts = instance_id.split('~');
+    let name = parts.next().unwrap_or_default();
+    if name.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Invalid location '{}': empty instance name",
+            location
+        ));
+    }
+
+    let mut access = InstanceAccess::Public;
+    let mut owner_id = None;
+    let mut region = None;
+    let mut can_request_invite = false;
+    let mut group_access = None;
+
+    for modifier in parts {
+        let (key, value) = match modifier.split_once('(') {
+            Some((key, rest)) => (key, rest.strip_suffix(')')),
+            None => (modifier, None),
+        };
+        match key {
+            "hidden" => access = InstanceAccess::FriendsPlus,
+            "friends" => access = InstanceAccess::Friends,
+            "private" => access = InstanceAccess::Invite,
+            "group" => access = InstanceAccess::Group,
+            "canRequestInvite" => can_request_invite = true,
+            "groupAccessType" => group_access = value,
+            "region" => region = value.map(str::to_string),
+            _ => {}
+        }
+        if matches!(key, "hidden" | "friends" | "private" | "group") {
+            owner_id = value.map(str::to_string);
+        }
+    }
+
+    if access == InstanceAccess::Invite && can_request_invite {
+        access = InstanceAccess::InvitePlus;
+    }
+    if access == InstanceAccess::Group {
+        access = match group_access {
+            Some("plus") => InstanceAccess::GroupPlus,
+            Some("public") => InstanceAccess::GroupPublic,
+            _ => InstanceAccess::Group,
+        };
+    }
+
+    Ok(Location::Instance(InstanceLocation {
+        world_id: world_id.to_string(),
+        instance_id: instance_id.to_string(),
+        name: name.to_string(),
+        access,
+        owner_id,
+        region,
+    }))
+}
+
+/// Parse a location and require it to be a world instance, explaining why otherwise
+/// `whose` describes the owner of the location in errors, e.g. "You are" or "Alice is"
+pub fn require_instance(location: &str, whose: &str) -> Result<InstanceLocation> {
+    match parse_location(location)? {
+        Location::Instance(instance) => Ok(instance),
+        Location::Offline => Err(anyhow::anyhow!("{} offline", whose)),
+        Location::Private => Err(anyhow::anyhow!(
+            "{} in a private world, so the location is hidden",
+            whose
+        )),
+        Location::Traveling => Err(anyhow::anyhow!(
+            "{} traveling between instances; try again once arrived",
+            whose
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_special_locations() {
+        assert_eq!(parse_location("offline").unwrap(), Location::Offline);
+        assert_eq!(parse_location("").unwrap(), Location::Offline);
+        assert_eq!(parse_location("private").unwrap(), Location::Private);
+        assert_eq!(parse_location("traveling").unwrap(), Location::Traveling);
+        assert!(parse_location("garbage").is_err());
+        assert!(parse_location("usr_x:123").is_err());
+    }
+
+    #[test]
+    fn test_parse_public_instance() {
+        let Location::Instance(instance) = parse_location("wrld_abc:12345").unwrap() else {
+            panic!("Expected instance");
+        };
+        assert_eq!(instance.world_id, "wrld_abc");
+        assert_eq!(instance.name, "12345");
+        assert_eq!(instance.access, InstanceAccess::Public);
+        assert_eq!(instance.owner_id, None);
+        assert_eq!(instance.location(), "wrld_abc:12345");
+    }
+
+    #[test]
+    fn test_parse_instance_modifiers() {
+        let location = "wrld_abc:999~private(usr_me)~canRequestInvite~region(eu)~nonce(x)";
+        let Location::Instance(instance) = parse_location(location).unwrap() else {
+            panic!("Expected instance");
+        };
+        assert_eq!(instance.access, InstanceAccess::InvitePlus);
+        assert_eq!(instance.owner_id.as_deref(), Some("usr_me"));
+        assert_eq!(instance.region.as_deref(), Some("eu"));
+        assert_eq!(
+            instance.instance_id,
+            "999~private(usr_me)~canRequestInvite~region(eu)~nonce(x)"
+        );
+
+        let Location::Instance(group) =
+            parse_location("wrld_abc:1~group(grp_x)~groupAccessType(plus)").unwrap()
+        else {
+            panic!("Expected instance");
+        };
+        assert_eq!(group.access, InstanceAccess::GroupPlus);
+        assert_eq!(group.owner_id.as_deref(), Some("grp_x"));
+
+        let Location::Instance(friends) = parse_location("wrld_abc:1~hidden(usr_x)").unwrap()
+        else {
+            panic!("Expected instance");
+        };
+        assert_eq!(friends.access, InstanceAccess::FriendsPlus);
+    }
+
+    #[test]
+    fn test_instance_type_names() {
+        assert_eq!(
+            InstanceAccess::from_type_name("friends+").unwrap(),
+            InstanceAccess::FriendsPlus
+        );
+        assert_eq!(
+            InstanceAccess::from_type_name("Invite+").unwrap(),
+            InstanceAccess::InvitePlus
+        );
+        assert!(InstanceAccess::from_type_name("group").unwrap().is_group());
+        assert!(InstanceAccess::from_type_name("everyone").is_err());
+
+        let Location::Instance(instance) = parse_location("wrld_abc:1~region(jp)").unwrap() else {
+            panic!("Expected instance");
+        };
+        assert_eq!(
+            instance.launch_url(),
+            "vrchat://launch?ref=vrchat.com&id=wrld_abc:1~region(jp)"
+        );
+        assert_eq!(
+            instance.web_url(),
+            "https://vrchat.com/home/launch?worldId=wrld_abc&instanceId=1~region(jp)"
+        );
+        assert_eq!(
+            web_launch_url("wrld_abc", None),
+            "https://vrchat.com/home/launch?worldId=wrld_abc"
+        );
+    }
+
+    #[test]
+    fn test_require_instance_errors() {
+        let error = require_instance("traveling", "You are").unwrap_err();
+        assert!(error.to_string().contains("traveling"));
+        let error = require_instance("private", "You are").unwrap_err();
+        assert!(error.to_string().contains("private world"));
+        assert!(require_instance("wrld_abc:1", "You are").is_ok());
+    }
+}
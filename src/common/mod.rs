@@ -1,8 +1,19 @@
 pub mod auth_client;
 pub mod command_utils;
+pub mod content_policy;
+pub mod disambiguation;
 pub mod display_options;
+pub mod events;
+pub mod favorites;
 pub mod formatter;
+pub mod friend_cache;
+pub mod friend_filter;
+pub mod hooks;
+pub mod location;
+pub mod name_matching;
 pub mod output_options;
+pub mod paginator;
+pub mod sorting;
 pub mod table;
 pub mod user_operations;
 pub mod utils;
@@ -0,0 +1,130 @@
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// Minimum similarity for a fuzzy match to be considered
+const FUZZY_THRESHOLD: f64 = 0.88;
+
+/// How well a candidate name matches the query
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum MatchQuality {
+    /// Similar after normalization, with a similarity score between 0 and 1
+    Fuzzy(f64),
+    /// Equal after case and accent folding
+    Normalized,
+    /// Byte-for-byte equal
+    Exact,
+}
+
+/// Fold a display name for comparison: compatibility decomposition, accents removed,
+/// lowercased and whitespace collapsed
+pub fn normalize_name(name: &str) -> String {
+    let folded: String = name
+        .nfkd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(|c| c.to_lowercase())
+        .collect();
+
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Compare a query with a candidate display name
+pub fn match_name(query: &str, candidate: &str) -> Option<MatchQuality> {
+    if query == candidate {
+        return Some(MatchQuality::Exact);
+    }
+
+    let query = normalize_name(query);
+    let candidate = normalize_name(candidate);
+
+    if query.is_empty() || candidate.is_empty() {
+        return None;
+    }
+
+    if query == candidate {
+        return Some(MatchQuality::Normalized);
+    }
+
+    let score = strsim::jaro_winkler(&query, &candidate);
+    if score >= FUZZY_THRESHOLD {
+        Some(MatchQuality::Fuzzy(score))
+    } else {
+        None
+    }
+}
+
+/// Rank candidates by how well they match the query; only the best tier is returned.
+/// `name_of` extracts the display name from a candidate.
+pub fn best_matches<'a, T>(
+    query: &str,
+    candidates: &'a [T],
+    name_of: impl Fn(&T) -> &str,
+) -> Vec<(&'a T, MatchQuality)> {
+    let mut matches: Vec<(&T, MatchQuality)> = candidates
+        .iter()
+        .filter_map(|candidate| match_name(query, name_of(candidate)).map(|q| (candidate, q)))
+        .collect();
+
+    matches.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+    let Some(best) = matches.first().map(|(_, quality)| *quality) else {
+        return matches;
+    };
+
+    matches.retain(|(_, quality)| match (best, quality) {
+        (MatchQuality::Exact, MatchQuality::Exact) => true,
+        (MatchQuality::Normalized, MatchQuality::Normalized) => true,
+        // Keep fuzzy matches that are nearly as good as the best one
+        (MatchQuality::Fuzzy(best), MatchQuality::Fuzzy(score)) => best - score < 0.03,
+        _ => false,
+    });
+
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("Ämélie"), "amelie");
+        assert_eq!(normalize_name("  Foo   Bar "), "foo bar");
+        assert_eq!(normalize_name("ＦＵＬＬＷＩＤＴＨ"), "fullwidth");
+        assert_eq!(normalize_name("ねこ"), "ねこ");
+    }
+
+    #[test]
+    fn test_match_name_quality() {
+        assert_eq!(match_name("Alice", "Alice"), Some(MatchQuality::Exact));
+        assert_eq!(match_name("alice", "ALICE"), Some(MatchQuality::Normalized));
+        assert_eq!(match_name("Zoe", "Zoë"), Some(MatchQuality::Normalized));
+        assert!(matches!(
+            match_name("Nekomasu", "Nekomasuu"),
+            Some(MatchQuality::Fuzzy(_))
+        ));
+        assert_eq!(match_name("Alice", "Bob"), None);
+        assert_eq!(match_name("", "Bob"), None);
+    }
+
+    #[test]
+    fn test_best_matches_prefers_exact() {
+        let names = vec!["alice", "Alice", "Alicee"];
+        let matches = best_matches("Alice", &names, |n| n);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(*matches[0].0, "Alice");
+    }
+
+    #[test]
+    fn test_best_matches_reports_ambiguity() {
+        let names = vec!["ALICE", "alice", "Bob"];
+        let matches = best_matches("Alice", &names, |n| n);
+        assert_eq!(matches.len(), 2);
+        assert!(matches.iter().all(|(_, q)| *q == MatchQuality::Normalized));
+    }
+
+    #[test]
+    fn test_best_matches_none() {
+        let names = vec!["Bob", "Carol"];
+        assert!(best_matches("Alice", &names, |n| n).is_empty());
+    }
+}
@@ -0,0 +1,148 @@
+use anyhow::Result;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use std::future::Future;
+use std::time::Duration;
+
+/// Page size used when walking pages; the largest most list endpoints accept
+pub const PAGE_SIZE: i32 = 100;
+
+/// Pages requested at once while walking
+const CONCURRENCY: usize = 5;
+
+/// Safety stop for `--all` in case an endpoint never returns an empty page
+const MAX_PAGES: usize = 100;
+
+/// Which results of a paginated endpoint to fetch
+#[derive(Debug, Clone, Copy)]
+pub struct Pagination {
+    /// Page size for a single-page request
+    pub limit: i32,
+    /// Offset of the first result
+    pub offset: i32,
+    /// Walk pages until the API returns an empty page
+    pub all: bool,
+    /// Walk pages until this many results were fetched
+    pub max: Option<usize>,
+}
+
+impl Pagination {
+    /// Whether more than one page may be requested
+    fn walks(&self) -> bool {
+        self.all || self.max.is_some()
+    }
+}
+
+/// Fetch results of a paginated endpoint
+/// `fetch_page` is called with `(offset, n)`. Without `--all`/`--max` a single page of
+/// `limit` results is fetched; otherwise pages of `PAGE_SIZE` are walked until one comes
+/// back empty or `max` results were collected.
+pub async fn fetch_pages<T, F, Fut>(pagination: Pagination, fetch_page: F) -> Result<Vec<T>>
+where
+    F: Fn(i32, i32) -> Fut,
+    Fut: Future<Output = Result<Vec<T>>>,
+{
+    if !pagination.walks() {
+        return fetch_page(pagination.offset, pagination.limit).await;
+    }
+
+    let pages = match pagination.max {
+        Some(max) => max.div_ceil(PAGE_SIZE as usize),
+        None => MAX_PAGES,
+    };
+    let batches = walk_pages(pagination.offset, PAGE_SIZE, pages, fetch_page).await?;
+
+    if pagination.all && pagination.max.is_none() && batches.len() == MAX_PAGES {
+        eprintln!(
+            "Warning: Stopped after {MAX_PAGES} pages; narrow the query or use --max to fetch a fixed number"
+        );
+    }
+
+    let mut items: Vec<T> = batches.into_iter().flatten().collect();
+    if let Some(max) = pagination.max {
+        items.truncate(max);
+    }
+    Ok(items)
+}
+
+/// Request up to `pages` pages of `page_size` results starting at `offset`
+/// Pages are requested with bounded concurrency and in order; the walk stops at the first
+/// empty page. Returns the non-empty pages.
+pub async fn walk_pages<T, F, Fut>(
+    offset: i32,
+    page_size: i32,
+    pages: usize,
+    fetch_page: F,
+) -> Result<Vec<Vec<T>>>
+where
+    F: Fn(i32, i32) -> Fut,
+    Fut: Future<Output = Result<Vec<T>>>,
+{
+    let offsets = (0..pages).map(|page| offset + page as i32 * page_size);
+
+    let fetch_page = &fetch_page;
+    stream::iter(offsets)
+        .map(|offset| async move {
+            // Small delay to avoid hammering the API too hard
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            fetch_page(offset, page_size).await
+        })
+        .buffered(CONCURRENCY)
+        .take_while(|result| {
+            // Stop the stream when we get an empty page
+            futures::future::ready(match result {
+                Ok(batch) => !batch.is_empty(),
+                Err(_) => true, // Let error bubble up later
+            })
+        })
+        .try_collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fake endpoint with `total` results
+    async fn fake_page(total: i32, offset: i32, n: i32) -> Result<Vec<i32>> {
+        Ok((offset..(offset + n).min(total)).collect())
+    }
+
+    fn pagination(all: bool, max: Option<usize>) -> Pagination {
+        Pagination {
+            limit: 20,
+            offset: 0,
+            all,
+            max,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_single_page() {
+        let items = fetch_pages(pagination(false, None), |offset, n| {
+            fake_page(250, offset, n)
+        })
+        .await
+        .unwrap();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn test_walk_all_pages() {
+        let items = fetch_pages(pagination(true, None), |offset, n| {
+            fake_page(250, offset, n)
+        })
+        .await
+        .unwrap();
+        assert_eq!(items, (0..250).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn test_walk_until_max() {
+        let items = fetch_pages(pagination(false, Some(130)), |offset, n| {
+            fake_page(250, offset, n)
+        })
+        .await
+        .unwrap();
+        assert_eq!(items, (0..130).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,132 @@
+use anyhow::Result;
+use std::cmp::Ordering;
+
+/// The value of an item for one sort field
+#[derive(Debug, Clone, PartialEq)]
+pub enum SortKey {
+    Text(String),
+    Number(i64),
+    /// No value; always sorted to the end
+    Missing,
+}
+
+impl SortKey {
+    /// Case-insensitive text key; `N/A` and empty strings count as missing
+    pub fn text(value: &str) -> Self {
+        match value {
+            "" | "N/A" => SortKey::Missing,
+            value => SortKey::Text(value.to_lowercase()),
+        }
+    }
+
+    /// Numeric key; `None` counts as missing
+    pub fn number(value: Option<i64>) -> Self {
+        value.map_or(SortKey::Missing, SortKey::Number)
+    }
+
+    fn compare(&self, other: &SortKey) -> Ordering {
+        match (self, other) {
+            (SortKey::Text(a), SortKey::Text(b)) => a.cmp(b),
+            (SortKey::Number(a), SortKey::Number(b)) => a.cmp(b),
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+/// Items that can be sorted by named fields
+pub trait Sortable {
+    /// Field names accepted by `--sort`
+    fn sort_fields() -> &'static [&'static str];
+
+    /// The value of one of `sort_fields` for this item
+    fn sort_key(&self, field: &str) -> SortKey;
+}
+
+/// Check a `--sort` value, returning the canonical field name
+pub fn parse_sort_field<T: Sortable>(field: &str) -> Result<&'static str> {
+    let wanted = field.to_lowercase();
+    T::sort_fields()
+        .iter()
+        .find(|name| **name == wanted)
+        .copied()
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid sort field '{}'. Valid fields: {}",
+                field,
+                T::sort_fields().join(", ")
+            )
+        })
+}
+
+/// Sort items by a field; items without a value stay at the end even when reversed
+pub fn sort_items<T: Sortable>(items: &mut [T], field: &str, reverse: bool) {
+    items.sort_by(|a, b| {
+        let a_key = a.sort_key(field);
+        let b_key = b.sort_key(field);
+        match (&a_key, &b_key) {
+            (SortKey::Missing, SortKey::Missing) => Ordering::Equal,
+            (SortKey::Missing, _) => Ordering::Greater,
+            (_, SortKey::Missing) => Ordering::Less,
+            _ if reverse => a_key.compare(&b_key).reverse(),
+            _ => a_key.compare(&b_key),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Item {
+        name: &'static str,
+        count: Option<i64>,
+    }
+
+    impl Sortable for Item {
+        fn sort_fields() -> &'static [&'static str] {
+            &["name", "count"]
+        }
+
+        fn sort_key(&self, field: &str) -> SortKey {
+            match field {
+                "count" => SortKey::number(self.count),
+                _ => SortKey::text(self.name),
+            }
+        }
+    }
+
+    fn items() -> Vec<Item> {
+        vec![
+            Item {
+                name: "beta",
+                count: Some(2),
+            },
+            Item {
+                name: "N/A",
+                count: None,
+            },
+            Item {
+                name: "Alpha",
+                count: Some(10),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_parse_sort_field() {
+        assert_eq!(parse_sort_field::<Item>("Count").unwrap(), "count");
+        assert!(parse_sort_field::<Item>("size").is_err());
+    }
+
+    #[test]
+    fn test_sort_items_keeps_missing_last() {
+        let mut sorted = items();
+        sort_items(&mut sorted, "name", false);
+        let names: Vec<&str> = sorted.iter().map(|item| item.name).collect();
+        assert_eq!(names, vec!["Alpha", "beta", "N/A"]);
+
+        sort_items(&mut sorted, "count", true);
+        let counts: Vec<Option<i64>> = sorted.iter().map(|item| item.count).collect();
+        assert_eq!(counts, vec![Some(10), Some(2), None]);
+    }
+}
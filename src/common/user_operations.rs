@@ -2,11 +2,16 @@ use anyhow::Result;
 use vrchatapi::apis;
 
 /// Common user identifier resolution logic
+/// `@short` identifiers are resolved from the aliases in the config without a network call
 pub async fn resolve_user_identifier(
     api_config: &vrchatapi::apis::configuration::Configuration,
     identifier: &str,
     use_direct_id: bool,
 ) -> Result<String> {
+    if let Some(alias) = identifier.strip_prefix('@') {
+        return resolve_alias(alias);
+    }
+
     if use_direct_id {
         // Use the identifier as-is (should be a user ID)
         if !crate::common::utils::is_valid_user_id(identifier) {
@@ -21,6 +26,17 @@ pub async fn resolve_user_identifier(
     }
 }
 
+/// Resolve an alias (without the leading `@`) to the user ID stored in the config
+pub fn resolve_alias(alias: &str) -> Result<String> {
+    let config = crate::config::Config::load()?;
+    config.alias(alias).map(str::to_string).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Unknown alias '@{}'. Use 'vrcli alias list' to see the available aliases.",
+            alias
+        )
+    })
+}
+
 /// Common user fetching logic with detailed error handling
 pub async fn fetch_user_by_resolved_id(
     api_config: &vrchatapi::apis::configuration::Configuration,
@@ -168,7 +184,14 @@ pub async fn diagnose_user_access_issues(
 
         // Step 3: Display name search test
         println!("Step 3: Attempting display name search...");
-        match crate::common::utils::resolve_display_name_to_user_id(api_config, identifier).await {
+        let name_history = crate::history::open_for_name_lookup();
+        match crate::common::utils::resolve_display_name_to_user_id(
+            api_config,
+            identifier,
+            name_history.as_ref(),
+        )
+        .await
+        {
             Ok(user_id) => {
                 println!("✅ Display name resolved to user ID: {user_id}");
 
@@ -1,3 +1,7 @@
+use super::disambiguation;
+use super::friend_cache;
+use super::name_matching::{self, MatchQuality};
+use crate::history::{DisplayNameRecord, HistoryStore};
 use anyhow::Result;
 use colored::*;
 use vrchatapi::apis;
@@ -104,6 +108,26 @@ pub fn format_text_with_width(text: &str, width: usize) -> String {
     }
 }
 
+/// Format a Unix timestamp as local date and time
+pub fn format_unix_timestamp(timestamp: i64) -> String {
+    match chrono::DateTime::from_timestamp(timestamp, 0) {
+        Some(datetime) => datetime
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string(),
+        None => "N/A".to_string(),
+    }
+}
+
+/// Format an RFC 3339 timestamp from the API as local date and time
+/// Unparseable values are returned unchanged
+pub fn format_api_timestamp(timestamp: &str) -> String {
+    match chrono::DateTime::parse_from_rfc3339(timestamp) {
+        Ok(datetime) => format_unix_timestamp(datetime.timestamp()),
+        Err(_) => timestamp.to_string(),
+    }
+}
+
 /// Validate user ID format
 pub fn is_valid_user_id(user_id: &str) -> bool {
     // Modern format: starts with "usr_" followed by UUID-like string
@@ -135,13 +159,29 @@ pub fn is_valid_user_id(user_id: &str) -> bool {
     false
 }
 
-/// Resolve display name to user ID using search API
+/// Resolve display name to user ID
+/// Friends are matched first (case- and accent-insensitive), then the global user search
+/// is used, then display names previously seen in the history store. Friends that only
+/// match fuzzily are offered for confirmation when nothing matched exactly.
+/// `name_history` is skipped when `None`.
 /// Returns the user ID if found, otherwise returns an error
 pub async fn resolve_display_name_to_user_id(
     api_config: &vrchatapi::apis::configuration::Configuration,
     display_name: &str,
+    name_history: Option<&HistoryStore>,
 ) -> Result<String> {
-    // Search for users by display name
+    // A previous disambiguation choice for exactly this name; `@` aliases are resolved by
+    // the caller and never consulted for bare names
+    if let Some(user_id) = disambiguation::lookup_remembered_name(display_name) {
+        return Ok(user_id);
+    }
+
+    let fuzzy_friends = match resolve_from_friends(api_config, display_name).await? {
+        FriendLookup::Resolved(user_id) => return Ok(user_id),
+        FriendLookup::Fuzzy(friends) => friends,
+    };
+
+    // Not an exact friend match: search for users by display name
     let search_results = match apis::users_api::search_users(
         api_config,
         Some(display_name),
@@ -161,30 +201,49 @@ pub async fn resolve_display_name_to_user_id(
         }
     };
 
-    if search_results.is_empty() {
+    if let Some(user) = exact_match(display_name, &search_results, |user| &user.display_name) {
+        return Ok(user.id.clone());
+    }
+
+    // The user may have renamed themselves since we last saw them
+    if let Some(user_id) = resolve_from_name_history(name_history, display_name)? {
+        return Ok(user_id);
+    }
+
+    if search_results.is_empty() && fuzzy_friends.is_empty() {
         return Err(anyhow::anyhow!(
             "No users found with display name '{}'",
             display_name
         ));
     }
 
-    // Look for exact match (case-insensitive)
-    let exact_match = search_results
-        .iter()
-        .find(|user| user.display_name.to_lowercase() == display_name.to_lowercase());
-
-    if let Some(user) = exact_match {
-        return Ok(user.id.clone());
+    // If no exact match, let an interactive user pick one of the similar friends or results
+    let is_fuzzy_friend = |user_id: &str| fuzzy_friends.iter().any(|(id, _)| id == user_id);
+    if disambiguation::is_interactive() {
+        let mut candidates = disambiguation::candidates_from_ids(api_config, &fuzzy_friends).await;
+        candidates.extend(
+            search_results
+                .iter()
+                .filter(|user| !is_fuzzy_friend(&user.id))
+                .map(disambiguation::UserCandidate::from_search),
+        );
+        return disambiguation::choose_user(display_name, candidates);
     }
 
-    // If no exact match, but we have results, show them as suggestions
-    let suggestions: Vec<String> = search_results
+    // Otherwise show them as suggestions, friends first
+    let suggestions: Vec<String> = fuzzy_friends
         .iter()
+        .map(|(id, display_name)| format!("  - {display_name} ({id}, friend)"))
+        .chain(
+            search_results
+                .iter()
+                .filter(|user| !is_fuzzy_friend(&user.id))
+                .map(|user| {
+                    let display_name = &user.display_name;
+                    format!("  - {display_name}")
+                }),
+        )
         .take(5)
-        .map(|user| {
-            let display_name = &user.display_name;
-            format!("  - {display_name}")
-        })
         .collect();
 
     Err(anyhow::anyhow!(
@@ -194,6 +253,177 @@ pub async fn resolve_display_name_to_user_id(
     ))
 }
 
+/// Result of matching a display name against the friend list
+enum FriendLookup {
+    /// A single friend matched exactly (or case- and accent-insensitively)
+    Resolved(String),
+    /// No exact match; (user ID, display name) of friends with a similar name, if any
+    Fuzzy(Vec<(String, String)>),
+}
+
+/// Friends matching a display name, split by match quality
+#[derive(Debug, PartialEq)]
+enum FriendMatch<'a, T> {
+    /// Exact or case- and accent-insensitive matches
+    Exact(Vec<&'a T>),
+    /// Only similar names; may be empty
+    Fuzzy(Vec<&'a T>),
+}
+
+/// Match a display name against friends; `name_of` extracts the display name
+fn match_friends<'a, T>(
+    display_name: &str,
+    friends: &'a [T],
+    name_of: impl Fn(&T) -> &str,
+) -> FriendMatch<'a, T> {
+    let matches = name_matching::best_matches(display_name, friends, name_of);
+    // Only the best tier is returned, so the first match tells the quality of all of them
+    let fuzzy = matches
+        .first()
+        .is_none_or(|(_, quality)| matches!(quality, MatchQuality::Fuzzy(_)));
+    let friends = matches.into_iter().map(|(friend, _)| friend).collect();
+    if fuzzy {
+        FriendMatch::Fuzzy(friends)
+    } else {
+        FriendMatch::Exact(friends)
+    }
+}
+
+/// Find the search result whose display name equals the query (case- and accent-insensitive)
+fn exact_match<'a, T>(
+    display_name: &str,
+    results: &'a [T],
+    name_of: impl Fn(&T) -> &str,
+) -> Option<&'a T> {
+    let wanted = name_matching::normalize_name(display_name);
+    results
+        .iter()
+        .find(|result| name_matching::normalize_name(name_of(result)) == wanted)
+}
+
+/// Resolve a display name against the (cached) friend list
+/// Returns an error when several friends match exactly
+async fn resolve_from_friends(
+    api_config: &vrchatapi::apis::configuration::Configuration,
+    display_name: &str,
+) -> Result<FriendLookup> {
+    let friends = match friend_cache::cached_friends(api_config).await {
+        Ok(friends) => friends,
+        Err(e) => {
+            eprintln!("Warning: Could not load friend list, using global search: {e}");
+            return Ok(FriendLookup::Fuzzy(Vec::new()));
+        }
+    };
+
+    let matches = match match_friends(display_name, &friends, |f| &f.display_name) {
+        FriendMatch::Exact(matches) => matches,
+        FriendMatch::Fuzzy(matches) => {
+            return Ok(FriendLookup::Fuzzy(
+                matches
+                    .iter()
+                    .map(|friend| (friend.id.clone(), friend.display_name.clone()))
+                    .collect(),
+            ))
+        }
+    };
+
+    match matches.as_slice() {
+        [friend] => Ok(FriendLookup::Resolved(friend.id.clone())),
+        _ if disambiguation::is_interactive() => {
+            let users: Vec<(String, String)> = matches
+                .iter()
+                .map(|friend| (friend.id.clone(), friend.display_name.clone()))
+                .collect();
+            let candidates = disambiguation::candidates_from_ids(api_config, &users).await;
+            disambiguation::choose_user(display_name, candidates).map(FriendLookup::Resolved)
+        }
+        _ => {
+            let candidates: Vec<String> = matches
+                .iter()
+                .map(|friend| format!("  - {} ({})", friend.display_name, friend.id))
+                .collect();
+            Err(anyhow::anyhow!(
+                "Display name '{}' matches several friends:\n{}\nUse --id with one of the user IDs.",
+                display_name,
+                candidates.join("\n")
+            ))
+        }
+    }
+}
+
+/// Resolve a display name using previously observed names from the history store
+/// Returns None when the name was never seen or the store is unavailable, and an error
+/// when the name is ambiguous
+fn resolve_from_name_history(
+    store: Option<&HistoryStore>,
+    display_name: &str,
+) -> Result<Option<String>> {
+    let Some(store) = store else {
+        return Ok(None);
+    };
+
+    let owners = match previous_name_owners(store, display_name) {
+        Ok(owners) => owners,
+        Err(e) => {
+            eprintln!("Warning: Could not search the name history: {e}");
+            return Ok(None);
+        }
+    };
+
+    match owners.as_slice() {
+        [] => Ok(None),
+        [(record, current_name)] => {
+            eprintln!(
+                "Note: '{}' was last seen as the display name of {} (now '{}') on {}",
+                display_name,
+                record.user_id,
+                current_name,
+                format_unix_timestamp(record.last_seen)
+            );
+            Ok(Some(record.user_id.clone()))
+        }
+        _ => {
+            let candidates: Vec<String> = owners
+                .iter()
+                .map(|(record, _)| {
+                    format!(
+                        "  - {} (last seen as '{}' on {})",
+                        record.user_id,
+                        record.display_name,
+                        format_unix_timestamp(record.last_seen)
+                    )
+                })
+                .collect();
+            Err(anyhow::anyhow!(
+                "Display name '{}' was previously used by several users:\n{}",
+                display_name,
+                candidates.join("\n")
+            ))
+        }
+    }
+}
+
+/// Users that used a display name, most recently seen first, with their latest name
+fn previous_name_owners(
+    store: &HistoryStore,
+    display_name: &str,
+) -> Result<Vec<(DisplayNameRecord, String)>> {
+    // Keep only the most recent record per user
+    let mut records = store.find_by_display_name(display_name)?;
+    let mut seen = std::collections::HashSet::new();
+    records.retain(|record| seen.insert(record.user_id.clone()));
+
+    records
+        .into_iter()
+        .map(|record| {
+            let current_name = store
+                .latest_display_name(&record.user_id)?
+                .unwrap_or_else(|| record.display_name.clone());
+            Ok((record, current_name))
+        })
+        .collect()
+}
+
 /// Resolve user identifier (either display name or user ID) to user ID
 /// If the input is already a valid user ID, return it as-is
 /// If the input looks like a display name, try to resolve it to user ID
@@ -207,7 +437,8 @@ pub async fn resolve_user_identifier(
     }
 
     // Otherwise, try to resolve as display name
-    resolve_display_name_to_user_id(api_config, identifier).await
+    let name_history = crate::history::open_for_name_lookup();
+    resolve_display_name_to_user_id(api_config, identifier, name_history.as_ref()).await
 }
 
 #[cfg(test)]
@@ -286,6 +517,21 @@ mod tests {
         assert_eq!(format_text_with_width("こんにちは", 6), "こ... ");
     }
 
+    #[test]
+    fn test_format_unix_timestamp() {
+        assert_eq!(format_unix_timestamp(i64::MAX), "N/A");
+        assert_eq!(format_unix_timestamp(0).len(), "1970-01-01 00:00".len());
+    }
+
+    #[test]
+    fn test_format_api_timestamp() {
+        assert_eq!(
+            format_api_timestamp("2024-01-01T00:00:00.000Z"),
+            format_unix_timestamp(1704067200)
+        );
+        assert_eq!(format_api_timestamp("not a date"), "not a date");
+    }
+
     #[test]
     fn test_is_valid_user_id_modern_format() {
         assert!(is_valid_user_id("usr_12345678-1234-1234-1234-123456789012"));
@@ -315,4 +561,56 @@ mod tests {
     // Note: resolve_display_name_to_user_id and resolve_user_identifier
     // require async API calls and will be tested in integration tests
     // with mocked responses
+
+    #[test]
+    fn test_fuzzy_friend_does_not_override_exact_search_match() {
+        let friends = [("usr_alice", "Alice")];
+        let search_results = [("usr_alice", "Alice"), ("usr_alicia", "Alicia")];
+
+        // "Alicia" is only similar to the friend "Alice", so it is not resolved to her
+        match match_friends("Alicia", &friends, |friend| friend.1) {
+            FriendMatch::Fuzzy(matches) => assert_eq!(matches, vec![&friends[0]]),
+            FriendMatch::Exact(_) => panic!("Expected only a fuzzy friend match"),
+        }
+        let user = exact_match("Alicia", &search_results, |user| user.1);
+        assert_eq!(user.map(|user| user.0), Some("usr_alicia"));
+
+        // An exact friend match still resolves without searching
+        assert_eq!(
+            match_friends("alice", &friends, |friend| friend.1),
+            FriendMatch::Exact(vec![&friends[0]])
+        );
+        assert_eq!(
+            match_friends("Bob", &friends, |friend| friend.1),
+            FriendMatch::Fuzzy(Vec::new())
+        );
+    }
+
+    #[test]
+    fn test_resolve_from_name_history() {
+        use crate::history::FriendObservation;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut store = HistoryStore::open_at(&dir.path().join("history.db")).unwrap();
+        let observation = |display_name: &str| FriendObservation {
+            user_id: "usr_a".to_string(),
+            display_name: display_name.to_string(),
+            status: "Active".to_string(),
+            location: "offline".to_string(),
+            platform: String::new(),
+        };
+        store.record_friends(&[observation("Alice")], 100).unwrap();
+        store.record_friends(&[observation("Alicia")], 200).unwrap();
+
+        // A previous name resolves to the user who renamed themselves
+        assert_eq!(
+            resolve_from_name_history(Some(&store), "alice").unwrap(),
+            Some("usr_a".to_string())
+        );
+        assert_eq!(
+            resolve_from_name_history(Some(&store), "Bob").unwrap(),
+            None
+        );
+        assert_eq!(resolve_from_name_history(None, "Alice").unwrap(), None);
+    }
 }
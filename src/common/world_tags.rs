@@ -1,5 +1,263 @@
+use anyhow::Result;
 use std::collections::HashMap;
 
+/// Tag categories shown by `worlds tags`: name, raw prefix and the suffixes with friendly names
+const TAG_CATEGORIES: &[(&str, &str, &[&str])] = &[
+    (
+        "Author",
+        "author_tag_",
+        &[
+            "game",
+            "social",
+            "art",
+            "music",
+            "dance",
+            "club",
+            "avatar",
+            "hangout",
+            "roleplay",
+            "udon",
+            "murder",
+            "horror",
+            "puzzle",
+            "exploration",
+            "parkour",
+            "comedy",
+            "educational",
+            "showcase",
+            "photomode",
+        ],
+    ),
+    (
+        "Feature",
+        "feature_",
+        &[
+            "drones_disabled",
+            "mirror_disabled",
+            "camera_disabled",
+            "udon_enabled",
+            "particle_system",
+        ],
+    ),
+    (
+        "Content",
+        "content_",
+        &[
+            "horror",
+            "violence",
+            "gore",
+            "adult",
+            "suggestive",
+            "drug_use",
+            "alcohol",
+            "gambling",
+        ],
+    ),
+    (
+        "Language",
+        "lang_",
+        &[
+            "eng", "en", "jpn", "ja", "kor", "ko", "chi", "zh", "spa", "es", "fra", "fr", "ger",
+            "de", "rus", "ru", "ita", "it", "por", "pt",
+        ],
+    ),
+    ("System", "system_", &["approved", "featured", "labs"]),
+    (
+        "Admin",
+        "admin_",
+        &[
+            "approved",
+            "featured",
+            "vrrat_community_takeover",
+            "community_spotlight",
+            "staff_pick",
+            "verified_creator",
+        ],
+    ),
+    (
+        "Platform",
+        "",
+        &[
+            "android_supported",
+            "ios_supported",
+            "pc_supported",
+            "quest_supported",
+        ],
+    ),
+];
+
+/// A tag with a friendly name
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct KnownTag {
+    pub category: &'static str,
+    pub tag: String,
+    pub name: String,
+}
+
+/// All tags that have friendly names, grouped by category
+pub fn known_tags() -> Vec<KnownTag> {
+    TAG_CATEGORIES
+        .iter()
+        .flat_map(|(category, prefix, suffixes)| {
+            suffixes.iter().map(move |suffix| {
+                let tag = format!("{prefix}{suffix}");
+                KnownTag {
+                    category,
+                    name: friendly_name(&tag),
+                    tag,
+                }
+            })
+        })
+        .collect()
+}
+
+/// The friendly name of a tag without its emoji marker
+fn friendly_name(tag: &str) -> String {
+    let formatted = format_single_tag(tag);
+    if formatted == tag {
+        return formatted;
+    }
+    formatted
+        .split_once(' ')
+        .map(|(_, name)| name.to_string())
+        .unwrap_or(formatted)
+}
+
+/// Fold a tag or name for comparison ("Murder Mystery" -> "murdermystery")
+fn fold(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+/// Whether the leading words of a name fold to `wanted` ("Horror Content" starts with "horror")
+fn starts_with_words(name: &str, wanted: &str) -> bool {
+    let mut folded = String::new();
+    name.split_whitespace().any(|word| {
+        folded.push_str(&fold(word));
+        folded == wanted
+    })
+}
+
+/// Map a friendly name ("Horror", "Quest") or raw tag to the raw tags it stands for
+pub fn resolve_tag(name: &str) -> Result<Vec<String>> {
+    let wanted = fold(name);
+    if wanted.is_empty() {
+        return Err(anyhow::anyhow!("Tag name cannot be empty"));
+    }
+    let mut tags: Vec<String> = known_tags()
+        .into_iter()
+        .filter(|known| fold(&known.name) == wanted || fold(&known.tag) == wanted)
+        .map(|known| known.tag)
+        .collect();
+
+    // Also match "Horror" against "Horror Content" style names when nothing matched exactly;
+    // only whole leading words count, so "a" does not match every name starting with A
+    if tags.is_empty() {
+        tags = known_tags()
+            .into_iter()
+            .filter(|known| starts_with_words(&known.name, &wanted))
+            .map(|known| known.tag)
+            .collect();
+    }
+
+    if tags.is_empty() {
+        // Unknown raw tags are used as-is
+        if name.contains('_') {
+            return Ok(vec![name.to_string()]);
+        }
+        return Err(anyhow::anyhow!(
+            "Unknown tag '{}'. Use 'vrcli worlds tags' to see the known tags, or give a raw tag",
+            name
+        ));
+    }
+
+    Ok(tags)
+}
+
+/// Prefixes of the tags a world author sets themselves
+const AUTHOR_SET_PREFIXES: &[&str] = &["author_tag_", "content_"];
+
+/// Replace the author and content tags of a world, keeping system, admin and other tags
+/// Plain words become `author_tag_<word>`; `author_tag_` and `content_` tags are used as
+/// given. Tags only VRChat can set, such as `system_` or `admin_` ones, are rejected.
+pub fn replace_author_tags(existing: &[String], author_tags: &[String]) -> Result<Vec<String>> {
+    let is_author_set = |tag: &str| {
+        AUTHOR_SET_PREFIXES
+            .iter()
+            .any(|prefix| tag.starts_with(prefix))
+    };
+    let is_reserved = |tag: &str| {
+        TAG_CATEGORIES
+            .iter()
+            .any(|(_, prefix, _)| !prefix.is_empty() && tag.starts_with(prefix))
+            || known_tags().iter().any(|known| known.tag == tag)
+    };
+
+    let mut tags: Vec<String> = existing
+        .iter()
+        .filter(|tag| !is_author_set(tag))
+        .cloned()
+        .collect();
+
+    for tag in author_tags {
+        let tag = tag.trim().to_lowercase();
+        if tag.is_empty() {
+            continue;
+        }
+        let tag = if is_author_set(&tag) {
+            tag
+        } else if is_reserved(&tag) {
+            return Err(anyhow::anyhow!(
+                "Tag '{}' cannot be set by the author; only author tags and content_ tags can",
+                tag
+            ));
+        } else {
+            format!("author_tag_{}", tag.replace(' ', "_"))
+        };
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    Ok(tags)
+}
+
+/// Client-side filter on world tags
+#[derive(Debug, Clone, Default)]
+pub struct TagFilter {
+    /// Each entry lists the alternatives for one `--has-tag`
+    required: Vec<Vec<String>>,
+    excluded: Vec<String>,
+}
+
+impl TagFilter {
+    /// Build a filter from friendly names or raw tags
+    pub fn new(has_tags: &[String], lacks_tags: &[String]) -> Result<Self> {
+        let required = has_tags
+            .iter()
+            .map(|name| resolve_tag(name))
+            .collect::<Result<Vec<_>>>()?;
+        let excluded = lacks_tags
+            .iter()
+            .map(|name| resolve_tag(name))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+        Ok(Self { required, excluded })
+    }
+
+    /// Whether a world with these tags passes the filter
+    pub fn matches(&self, tags: &[String]) -> bool {
+        self.required
+            .iter()
+            .all(|alternatives| alternatives.iter().any(|tag| tags.contains(tag)))
+            && !self.excluded.iter().any(|tag| tags.contains(tag))
+    }
+}
+
 /// Format VRChat world tags in a user-friendly way
 pub fn format_world_tags(tags: &[String]) -> String {
     if tags.is_empty() {
@@ -215,8 +473,67 @@ mod tests {
         assert_eq!(result, "📝 Game, 👷 Admin Approved, ⚙️ No Drones");
     }
 
+    #[test]
+    fn test_resolve_friendly_tags() {
+        assert_eq!(resolve_tag("Quest").unwrap(), vec!["quest_supported"]);
+        assert_eq!(
+            resolve_tag("horror").unwrap(),
+            vec!["author_tag_horror", "content_horror"]
+        );
+        assert_eq!(
+            resolve_tag("murder mystery").unwrap(),
+            vec!["author_tag_murder"]
+        );
+        assert_eq!(resolve_tag("custom_tag").unwrap(), vec!["custom_tag"]);
+        assert!(resolve_tag("Nonexistent").is_err());
+        assert!(resolve_tag("a").is_err());
+        assert!(resolve_tag("").is_err());
+    }
+
+    #[test]
+    fn test_tag_filter() {
+        let filter = TagFilter::new(&["Game".to_string()], &["Horror".to_string()]).unwrap();
+        let game = vec!["author_tag_game".to_string()];
+        let horror_game = vec!["author_tag_game".to_string(), "content_horror".to_string()];
+        assert!(filter.matches(&game));
+        assert!(!filter.matches(&horror_game));
+        assert!(!filter.matches(&[]));
+        assert!(TagFilter::default().matches(&[]));
+    }
+
     #[test]
     fn test_empty_tags() {
         assert_eq!(format_world_tags(&[]), "None");
     }
+
+    #[test]
+    fn test_replace_author_tags() {
+        let existing = vec![
+            "author_tag_game".to_string(),
+            "content_gore".to_string(),
+            "system_approved".to_string(),
+        ];
+        let tags = replace_author_tags(
+            &existing,
+            &[
+                "Horror".to_string(),
+                "content_horror".to_string(),
+                "horror".to_string(),
+                " ".to_string(),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            tags,
+            vec!["system_approved", "author_tag_horror", "content_horror"]
+        );
+        assert_eq!(
+            replace_author_tags(&existing, &[]).unwrap(),
+            vec!["system_approved"]
+        );
+
+        for reserved in ["system_approved", "admin_featured", "quest_supported"] {
+            assert!(replace_author_tags(&existing, &[reserved.to_string()]).is_err());
+        }
+    }
 }
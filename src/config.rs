@@ -1,5 +1,6 @@
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 
@@ -15,27 +16,112 @@ pub enum AuthMethod {
     },
 }
 
+/// A shell command run when a matching watch event occurs
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct HookConfig {
+    /// Event name, e.g. `friend-online`, `friend-location`, `notification-invite`
+    pub event: String,
+    /// Command executed through the system shell
+    pub command: String,
+    /// Only fire for these users (user IDs or display names); empty means everyone
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<String>,
+    /// Only fire when the event location is in this world
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub world: Option<String>,
+    /// Extra environment variables; values may contain `{field}` placeholders
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+}
+
+/// World content hidden from listings, e.g. while sharing a screen
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ContentPolicy {
+    /// World tags to hide; friendly names such as `adult` or `Gore` work too
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hide: Vec<String>,
+}
+
+impl ContentPolicy {
+    pub fn is_empty(&self) -> bool {
+        self.hide.is_empty()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Config {
     pub auth_method: AuthMethod,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hooks: Vec<HookConfig>,
+    /// Short names mapped to user IDs
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aliases: BTreeMap<String, String>,
+    /// Display names mapped to the user picked for them in the disambiguation prompt
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub remembered_names: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "ContentPolicy::is_empty")]
+    pub content_policy: ContentPolicy,
 }
 
 impl Config {
     pub fn new_password(username: String, password: String) -> Self {
-        Self {
-            auth_method: AuthMethod::Password { username, password },
-        }
+        Self::new(AuthMethod::Password { username, password })
     }
 
     pub fn new_cookie(auth_cookie: String, two_fa_cookie: Option<String>) -> Self {
+        Self::new(AuthMethod::Cookie {
+            auth_cookie,
+            two_fa_cookie,
+        })
+    }
+
+    fn new(auth_method: AuthMethod) -> Self {
         Self {
-            auth_method: AuthMethod::Cookie {
-                auth_cookie,
-                two_fa_cookie,
-            },
+            auth_method,
+            hooks: Vec::new(),
+            aliases: BTreeMap::new(),
+            remembered_names: BTreeMap::new(),
+            content_policy: ContentPolicy::default(),
         }
     }
 
+    /// Look up the user ID stored for an alias
+    pub fn alias(&self, name: &str) -> Option<&str> {
+        self.aliases.get(name).map(String::as_str)
+    }
+
+    /// Store an alias, returning the user ID it previously pointed to
+    pub fn set_alias(&mut self, name: &str, user_id: &str) -> Option<String> {
+        self.aliases.insert(name.to_string(), user_id.to_string())
+    }
+
+    /// Remove an alias, returning the user ID it pointed to
+    pub fn remove_alias(&mut self, name: &str) -> Option<String> {
+        self.aliases.remove(name)
+    }
+
+    /// Look up the user picked earlier for an ambiguous display name
+    pub fn remembered_name(&self, display_name: &str) -> Option<&str> {
+        self.remembered_names.get(display_name).map(String::as_str)
+    }
+
+    /// Remember the user picked for an ambiguous display name
+    pub fn remember_name(&mut self, display_name: &str, user_id: &str) {
+        self.remembered_names
+            .insert(display_name.to_string(), user_id.to_string());
+    }
+
+    /// Forget the user picked for a display name, returning their user ID
+    pub fn forget_name(&mut self, display_name: &str) -> Option<String> {
+        self.remembered_names.remove(display_name)
+    }
+
+    /// Replace the stored credentials while keeping the rest of an existing config
+    pub fn with_credentials_from(mut self, fresh: Config) -> Self {
+        self.auth_method = fresh.auth_method;
+        self
+    }
+
     pub fn load() -> Result<Self> {
         let config_path = get_config_path()?;
         if !config_path.exists() {
@@ -201,7 +287,105 @@ mod tests {
         // If it succeeds, that means a config file exists, which is also valid
     }
 
+    #[test]
+    fn test_config_without_hooks_deserializes() {
+        let json = r#"{"auth_method": {"Cookie": {"auth_cookie": "c", "two_fa_cookie": null}}}"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert!(config.hooks.is_empty());
+    }
+
+    #[test]
+    fn test_config_hooks_deserialization() {
+        let json = r#"
+        {
+            "auth_method": {"Cookie": {"auth_cookie": "c", "two_fa_cookie": null}},
+            "hooks": [
+                {
+                    "event": "friend-online",
+                    "users": ["usr_12345678-1234-1234-1234-123456789012"],
+                    "command": "notify-send online",
+                    "env": {"MESSAGE": "{display_name} is online"}
+                },
+                {"event": "notification-invite", "command": "cat"}
+            ]
+        }"#;
+
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(config.hooks.len(), 2);
+        assert_eq!(config.hooks[0].event, "friend-online");
+        assert_eq!(config.hooks[0].users.len(), 1);
+        assert_eq!(
+            config.hooks[0].env.get("MESSAGE").map(String::as_str),
+            Some("{display_name} is online")
+        );
+        assert!(config.hooks[1].users.is_empty());
+        assert_eq!(config.hooks[1].world, None);
+    }
+
+    #[test]
+    fn test_with_credentials_from_keeps_hooks() {
+        let mut existing = Config::new_password("old".to_string(), "old".to_string());
+        existing.hooks.push(HookConfig {
+            event: "friend-online".to_string(),
+            command: "true".to_string(),
+            users: Vec::new(),
+            world: None,
+            env: BTreeMap::new(),
+        });
+
+        let updated = existing.with_credentials_from(Config::new_cookie("new".to_string(), None));
+        assert_eq!(updated.hooks.len(), 1);
+        assert!(matches!(updated.auth_method, AuthMethod::Cookie { .. }));
+    }
+
     // Note: Testing save() and load() with actual file I/O requires more complex setup
     // with temporary directories and mocking the config path. This would be better
     // suited for integration tests.
+
+    #[test]
+    fn test_config_aliases() {
+        let mut config = Config::new_cookie("c".to_string(), None);
+        assert_eq!(config.alias("neko"), None);
+        assert_eq!(config.set_alias("neko", "usr_a"), None);
+        assert_eq!(config.set_alias("neko", "usr_b"), Some("usr_a".to_string()));
+        assert_eq!(config.alias("neko"), Some("usr_b"));
+
+        let json = serde_json::to_string(&config).unwrap();
+        let deserialized: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.alias("neko"), Some("usr_b"));
+
+        assert_eq!(config.remove_alias("neko"), Some("usr_b".to_string()));
+        assert_eq!(config.remove_alias("neko"), None);
+        assert!(config.aliases.is_empty());
+    }
+
+    #[test]
+    fn test_config_remembered_names_are_separate_from_aliases() {
+        let mut config = Config::new_cookie("c".to_string(), None);
+        config.remember_name("Neko Chan", "usr_a");
+        assert_eq!(config.remembered_name("Neko Chan"), Some("usr_a"));
+        assert_eq!(config.alias("Neko Chan"), None);
+
+        let json = serde_json::to_string(&config).unwrap();
+        let deserialized: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.remembered_name("Neko Chan"), Some("usr_a"));
+
+        assert_eq!(config.forget_name("Neko Chan"), Some("usr_a".to_string()));
+        assert!(config.remembered_names.is_empty());
+    }
+
+    #[test]
+    fn test_config_content_policy() {
+        let json = r#"{"auth_method":{"Cookie":{"auth_cookie":"c","two_fa_cookie":null}}}"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert!(config.content_policy.is_empty());
+        assert!(!serde_json::to_string(&config)
+            .unwrap()
+            .contains("content_policy"));
+
+        let json = r#"{"auth_method":{"Cookie":{"auth_cookie":"c","two_fa_cookie":null}},
+            "content_policy":{"hide":["adult","content_gore"]}}"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(config.content_policy.hide, vec!["adult", "content_gore"]);
+    }
 }
@@ -0,0 +1,711 @@
+use anyhow::{anyhow, Result};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A single observed friend state
+#[derive(Debug, Clone, PartialEq)]
+pub struct FriendObservation {
+    pub user_id: String,
+    pub display_name: String,
+    pub status: String,
+    pub location: String,
+    pub platform: String,
+}
+
+impl From<&vrchatapi::models::LimitedUserFriend> for FriendObservation {
+    fn from(friend: &vrchatapi::models::LimitedUserFriend) -> Self {
+        Self {
+            user_id: friend.id.clone(),
+            display_name: friend.display_name.clone(),
+            status: crate::common::utils::format_user_status(&friend.status, false),
+            location: friend.location.clone(),
+            platform: friend.last_platform.clone(),
+        }
+    }
+}
+
+/// A stored span of time during which a friend's state did not change
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryEntry {
+    pub user_id: String,
+    pub display_name: String,
+    pub status: String,
+    pub location: String,
+    pub platform: String,
+    /// Unix timestamp of the first observation of this state
+    pub first_seen: i64,
+    /// Unix timestamp of the most recent observation of this state
+    pub last_seen: i64,
+    /// Number of times this state was observed
+    pub samples: i64,
+}
+
+impl HistoryEntry {
+    /// Whether the friend was in-game (or on the website) during this span
+    pub fn is_online(&self) -> bool {
+        is_online_location(&self.location)
+    }
+}
+
+/// Aggregated statistics for a single friend
+#[derive(Debug, Clone, Serialize)]
+pub struct FriendStats {
+    pub user_id: String,
+    pub display_name: String,
+    pub first_seen: i64,
+    pub last_seen: i64,
+    pub last_online: Option<i64>,
+    pub total_samples: i64,
+    pub online_samples: i64,
+    /// (platform, samples) sorted by samples, most frequent first
+    pub platforms: Vec<(String, i64)>,
+    /// (world ID, samples, visits) sorted by samples, most frequent first
+    pub worlds: Vec<(String, i64, i64)>,
+}
+
+/// A display name observed for a user and when it was seen
+#[derive(Debug, Clone, Serialize)]
+pub struct DisplayNameRecord {
+    pub user_id: String,
+    pub display_name: String,
+    pub first_seen: i64,
+    pub last_seen: i64,
+}
+
+/// One poll of a watched world's statistics
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WorldSample {
+    pub world_id: String,
+    pub world_name: String,
+    /// Unix timestamp of the poll
+    pub observed_at: i64,
+    pub occupants: Option<i64>,
+    pub visits: i64,
+    pub favorites: Option<i64>,
+    pub version: i64,
+    /// `updated_at` as reported by the API
+    pub updated_at: String,
+}
+
+impl From<&vrchatapi::models::World> for WorldSample {
+    fn from(world: &vrchatapi::models::World) -> Self {
+        Self {
+            world_id: world.id.clone(),
+            world_name: world.name.clone(),
+            observed_at: chrono::Utc::now().timestamp(),
+            occupants: world.occupants.map(i64::from),
+            visits: i64::from(world.visits),
+            favorites: world.favorites.map(i64::from),
+            version: i64::from(world.version),
+            updated_at: world.updated_at.clone(),
+        }
+    }
+}
+
+impl WorldSample {
+    /// Header line matching `to_csv_row`
+    pub const CSV_HEADER: &'static str =
+        "world_id,world_name,observed_at,occupants,visits,favorites,version,updated_at";
+
+    /// Format the sample as a CSV row; the timestamp is written as RFC 3339
+    pub fn to_csv_row(&self) -> String {
+        let observed_at = chrono::DateTime::from_timestamp(self.observed_at, 0)
+            .map(|time| time.to_rfc3339())
+            .unwrap_or_else(|| self.observed_at.to_string());
+        let optional = |value: Option<i64>| value.map(|v| v.to_string()).unwrap_or_default();
+
+        [
+            csv_field(&self.world_id),
+            csv_field(&self.world_name),
+            observed_at,
+            optional(self.occupants),
+            self.visits.to_string(),
+            optional(self.favorites),
+            self.version.to_string(),
+            csv_field(&self.updated_at),
+        ]
+        .join(",")
+    }
+}
+
+/// Local SQLite store of observed friend states and watched world statistics
+pub struct HistoryStore {
+    conn: Connection,
+}
+
+impl HistoryStore {
+    /// Open the history database at the default location
+    pub fn open() -> Result<Self> {
+        let path = get_history_path()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        Self::open_at(&path)
+    }
+
+    /// Open (or create) the history database at a specific path
+    pub fn open_at(path: &Path) -> Result<Self> {
+        let conn = Connection::open(path)?;
+        let store = Self { conn };
+        store.init()?;
+        Ok(store)
+    }
+
+    /// Create tables if they don't exist yet
+    fn init(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS friend_observations (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                user_id TEXT NOT NULL,
+                display_name TEXT NOT NULL,
+                status TEXT NOT NULL,
+                location TEXT NOT NULL,
+                platform TEXT NOT NULL,
+                first_seen INTEGER NOT NULL,
+                last_seen INTEGER NOT NULL,
+                samples INTEGER NOT NULL DEFAULT 1
+            );
+            CREATE INDEX IF NOT EXISTS idx_friend_observations_user
+                ON friend_observations (user_id, last_seen);
+            CREATE TABLE IF NOT EXISTS world_samples (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                world_id TEXT NOT NULL,
+                world_name TEXT NOT NULL,
+                observed_at INTEGER NOT NULL,
+                occupants INTEGER,
+                visits INTEGER NOT NULL,
+                favorites INTEGER,
+                version INTEGER NOT NULL,
+                updated_at TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_world_samples_world
+                ON world_samples (world_id, observed_at);",
+        )?;
+        Ok(())
+    }
+
+    /// Record observed friend states. Unchanged states extend the latest span,
+    /// changed states start a new one.
+    pub fn record_friends(
+        &mut self,
+        observations: &[FriendObservation],
+        observed_at: i64,
+    ) -> Result<()> {
+        let tx = self.conn.transaction()?;
+
+        for observation in observations {
+            let latest: Option<(i64, String, String, String, String)> = tx
+                .query_row(
+                    "SELECT id, display_name, status, location, platform
+                     FROM friend_observations
+                     WHERE user_id = ?1
+                     ORDER BY last_seen DESC, id DESC
+                     LIMIT 1",
+                    params![observation.user_id],
+                    |row| {
+                        Ok((
+                            row.get(0)?,
+                            row.get(1)?,
+                            row.get(2)?,
+                            row.get(3)?,
+                            row.get(4)?,
+                        ))
+                    },
+                )
+                .optional()?;
+
+            match latest {
+                Some((id, display_name, status, location, platform))
+                    if display_name == observation.display_name
+                        && status == observation.status
+                        && location == observation.location
+                        && platform == observation.platform =>
+                {
+                    tx.execute(
+                        "UPDATE friend_observations
+                         SET last_seen = MAX(last_seen, ?1), samples = samples + 1
+                         WHERE id = ?2",
+                        params![observed_at, id],
+                    )?;
+                }
+                _ => {
+                    tx.execute(
+                        "INSERT INTO friend_observations
+                         (user_id, display_name, status, location, platform, first_seen, last_seen)
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?6)",
+                        params![
+                            observation.user_id,
+                            observation.display_name,
+                            observation.status,
+                            observation.location,
+                            observation.platform,
+                            observed_at
+                        ],
+                    )?;
+                }
+            }
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Get the recorded history of a user, newest first
+    pub fn user_history(&self, user_id: &str, limit: Option<usize>) -> Result<Vec<HistoryEntry>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT user_id, display_name, status, location, platform, first_seen, last_seen, samples
+             FROM friend_observations
+             WHERE user_id = ?1
+             ORDER BY last_seen DESC, id DESC
+             LIMIT ?2",
+        )?;
+
+        let limit = limit.map(|l| l as i64).unwrap_or(-1);
+        let entries = stmt
+            .query_map(params![user_id, limit], row_to_entry)?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(entries)
+    }
+
+    /// Get aggregated statistics for one user
+    pub fn user_stats(&self, user_id: &str) -> Result<Option<FriendStats>> {
+        let entries = self.user_history(user_id, None)?;
+        Ok(aggregate_stats(&entries))
+    }
+
+    /// Get every display name observed for a user, most recently used first
+    pub fn display_names(&self, user_id: &str) -> Result<Vec<DisplayNameRecord>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT user_id, display_name, MIN(first_seen), MAX(last_seen)
+             FROM friend_observations
+             WHERE user_id = ?1
+             GROUP BY user_id, display_name
+             ORDER BY MAX(last_seen) DESC",
+        )?;
+
+        let records = stmt
+            .query_map(params![user_id], row_to_name_record)?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(records)
+    }
+
+    /// Find users that were ever seen with the given display name (case-insensitive),
+    /// most recently seen first
+    pub fn find_by_display_name(&self, display_name: &str) -> Result<Vec<DisplayNameRecord>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT user_id, display_name, MIN(first_seen), MAX(last_seen)
+             FROM friend_observations
+             GROUP BY user_id, display_name
+             ORDER BY MAX(last_seen) DESC",
+        )?;
+
+        let wanted = display_name.to_lowercase();
+        let mut records = Vec::new();
+        for record in stmt.query_map([], row_to_name_record)? {
+            let record = record?;
+            if record.display_name.to_lowercase() == wanted {
+                records.push(record);
+            }
+        }
+        Ok(records)
+    }
+
+    /// Get the most recently observed display name of a user
+    pub fn latest_display_name(&self, user_id: &str) -> Result<Option<String>> {
+        Ok(self
+            .display_names(user_id)?
+            .into_iter()
+            .next()
+            .map(|record| record.display_name))
+    }
+
+    /// Get aggregated statistics for every recorded user
+    pub fn all_stats(&self) -> Result<Vec<FriendStats>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT user_id, display_name, status, location, platform, first_seen, last_seen, samples
+             FROM friend_observations
+             ORDER BY last_seen DESC, id DESC",
+        )?;
+
+        let mut by_user: HashMap<String, Vec<HistoryEntry>> = HashMap::new();
+        for entry in stmt.query_map([], row_to_entry)? {
+            let entry = entry?;
+            by_user
+                .entry(entry.user_id.clone())
+                .or_default()
+                .push(entry);
+        }
+
+        Ok(by_user
+            .values()
+            .filter_map(|entries| aggregate_stats(entries))
+            .collect())
+    }
+
+    /// Record one poll of a watched world
+    pub fn record_world_sample(&self, sample: &WorldSample) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO world_samples
+             (world_id, world_name, observed_at, occupants, visits, favorites, version, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                sample.world_id,
+                sample.world_name,
+                sample.observed_at,
+                sample.occupants,
+                sample.visits,
+                sample.favorites,
+                sample.version,
+                sample.updated_at
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Get the recorded samples of a world, oldest first
+    pub fn world_samples(&self, world_id: &str, since: Option<i64>) -> Result<Vec<WorldSample>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT world_id, world_name, observed_at, occupants, visits, favorites, version, updated_at
+             FROM world_samples
+             WHERE world_id = ?1 AND observed_at >= ?2
+             ORDER BY observed_at ASC, id ASC",
+        )?;
+
+        let samples = stmt
+            .query_map(params![world_id, since.unwrap_or(i64::MIN)], row_to_sample)?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(samples)
+    }
+
+    /// Get the most recent sample of a world
+    pub fn latest_world_sample(&self, world_id: &str) -> Result<Option<WorldSample>> {
+        let sample = self
+            .conn
+            .query_row(
+                "SELECT world_id, world_name, observed_at, occupants, visits, favorites, version, updated_at
+                 FROM world_samples
+                 WHERE world_id = ?1
+                 ORDER BY observed_at DESC, id DESC
+                 LIMIT 1",
+                params![world_id],
+                row_to_sample,
+            )
+            .optional()?;
+        Ok(sample)
+    }
+}
+
+/// Convert a database row to a WorldSample
+fn row_to_sample(row: &rusqlite::Row<'_>) -> rusqlite::Result<WorldSample> {
+    Ok(WorldSample {
+        world_id: row.get(0)?,
+        world_name: row.get(1)?,
+        observed_at: row.get(2)?,
+        occupants: row.get(3)?,
+        visits: row.get(4)?,
+        favorites: row.get(5)?,
+        version: row.get(6)?,
+        updated_at: row.get(7)?,
+    })
+}
+
+/// Quote a CSV field when it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Convert a database row to a HistoryEntry
+fn row_to_entry(row: &rusqlite::Row<'_>) -> rusqlite::Result<HistoryEntry> {
+    Ok(HistoryEntry {
+        user_id: row.get(0)?,
+        display_name: row.get(1)?,
+        status: row.get(2)?,
+        location: row.get(3)?,
+        platform: row.get(4)?,
+        first_seen: row.get(5)?,
+        last_seen: row.get(6)?,
+        samples: row.get(7)?,
+    })
+}
+
+/// Convert a grouped database row to a DisplayNameRecord
+fn row_to_name_record(row: &rusqlite::Row<'_>) -> rusqlite::Result<DisplayNameRecord> {
+    Ok(DisplayNameRecord {
+        user_id: row.get(0)?,
+        display_name: row.get(1)?,
+        first_seen: row.get(2)?,
+        last_seen: row.get(3)?,
+    })
+}
+
+/// Aggregate entries of a single user (newest first) into statistics
+fn aggregate_stats(entries: &[HistoryEntry]) -> Option<FriendStats> {
+    let latest = entries.first()?;
+
+    let mut platforms: HashMap<String, i64> = HashMap::new();
+    let mut worlds: HashMap<String, (i64, i64)> = HashMap::new();
+    let mut online_samples = 0;
+
+    for entry in entries.iter().filter(|e| e.is_online()) {
+        online_samples += entry.samples;
+        *platforms.entry(entry.platform.clone()).or_default() += entry.samples;
+
+        if let Some(world_id) = crate::common::events::world_id_of(&entry.location) {
+            let world = worlds.entry(world_id).or_default();
+            world.0 += entry.samples;
+            world.1 += 1;
+        }
+    }
+
+    let mut platforms: Vec<(String, i64)> = platforms.into_iter().collect();
+    platforms.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    let mut worlds: Vec<(String, i64, i64)> =
+        worlds.into_iter().map(|(id, (s, v))| (id, s, v)).collect();
+    worlds.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    Some(FriendStats {
+        user_id: latest.user_id.clone(),
+        display_name: latest.display_name.clone(),
+        first_seen: entries.iter().map(|e| e.first_seen).min()?,
+        last_seen: latest.last_seen,
+        last_online: entries
+            .iter()
+            .filter(|e| e.is_online())
+            .map(|e| e.last_seen)
+            .max(),
+        total_samples: entries.iter().map(|e| e.samples).sum(),
+        online_samples,
+        platforms,
+        worlds,
+    })
+}
+
+/// Whether a location string means the user is online
+pub fn is_online_location(location: &str) -> bool {
+    !location.is_empty() && location != "offline"
+}
+
+/// Record fetched friends in the history store, warning instead of failing
+pub fn record_observed_friends(friends: &[vrchatapi::models::LimitedUserFriend]) {
+    let observations: Vec<FriendObservation> =
+        friends.iter().map(FriendObservation::from).collect();
+    record_observations(&observations);
+}
+
+/// Record observations in the history store, warning instead of failing
+pub fn record_observations(observations: &[FriendObservation]) {
+    if observations.is_empty() {
+        return;
+    }
+
+    let result = HistoryStore::open()
+        .and_then(|mut store| store.record_friends(observations, chrono::Utc::now().timestamp()));
+
+    if let Err(e) = result {
+        eprintln!("Warning: Failed to record friend history: {e}");
+    }
+}
+
+/// Open the history store for display name lookups, warning instead of failing
+/// Resolution works without it, only renamed users cannot be found
+pub fn open_for_name_lookup() -> Option<HistoryStore> {
+    HistoryStore::open()
+        .map_err(|e| eprintln!("Warning: Could not open the name history: {e}"))
+        .ok()
+}
+
+fn get_history_path() -> Result<PathBuf> {
+    let data_dir = dirs::data_dir().ok_or_else(|| anyhow!("Could not find data directory"))?;
+
+    Ok(data_dir.join("vrcli").join("history.db"))
+}
+
+#[cfg(test)]
+impl WorldSample {
+    /// A sample of the same test world at `observed_at`, at version 3
+    pub(crate) fn fixture(observed_at: i64, occupants: Option<i64>) -> Self {
+        WorldSample {
+            world_id: "wrld_x".to_string(),
+            world_name: "Cafe, \"Night\"".to_string(),
+            observed_at,
+            occupants,
+            visits: 1000,
+            favorites: Some(50),
+            version: 3,
+            updated_at: "2024-01-01T00:00:00.000Z".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn observation(location: &str, platform: &str) -> FriendObservation {
+        FriendObservation {
+            user_id: "usr_a".to_string(),
+            display_name: "Alice".to_string(),
+            status: "Active".to_string(),
+            location: location.to_string(),
+            platform: platform.to_string(),
+        }
+    }
+
+    fn temp_store() -> (TempDir, HistoryStore) {
+        let dir = TempDir::new().unwrap();
+        let store = HistoryStore::open_at(&dir.path().join("history.db")).unwrap();
+        (dir, store)
+    }
+
+    #[test]
+    fn test_unchanged_state_extends_span() {
+        let (_dir, mut store) = temp_store();
+        let obs = observation("wrld_x:1", "standalonewindows");
+        store
+            .record_friends(std::slice::from_ref(&obs), 100)
+            .unwrap();
+        store.record_friends(&[obs], 200).unwrap();
+
+        let history = store.user_history("usr_a", None).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].first_seen, 100);
+        assert_eq!(history[0].last_seen, 200);
+        assert_eq!(history[0].samples, 2);
+    }
+
+    #[test]
+    fn test_changed_state_starts_new_span() {
+        let (_dir, mut store) = temp_store();
+        store
+            .record_friends(&[observation("wrld_x:1", "standalonewindows")], 100)
+            .unwrap();
+        store
+            .record_friends(&[observation("wrld_y:2", "android")], 200)
+            .unwrap();
+
+        let history = store.user_history("usr_a", None).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].location, "wrld_y:2");
+        assert_eq!(store.user_history("usr_a", Some(1)).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_user_stats() {
+        let (_dir, mut store) = temp_store();
+        store
+            .record_friends(&[observation("wrld_x:1", "standalonewindows")], 100)
+            .unwrap();
+        store
+            .record_friends(&[observation("wrld_x:1", "standalonewindows")], 150)
+            .unwrap();
+        store
+            .record_friends(&[observation("wrld_y:2", "android")], 200)
+            .unwrap();
+        store
+            .record_friends(&[observation("offline", "android")], 300)
+            .unwrap();
+
+        let stats = store.user_stats("usr_a").unwrap().unwrap();
+        assert_eq!(stats.last_online, Some(200));
+        assert_eq!(stats.last_seen, 300);
+        assert_eq!(stats.online_samples, 3);
+        assert_eq!(stats.total_samples, 4);
+        assert_eq!(stats.platforms[0], ("standalonewindows".to_string(), 2));
+        assert_eq!(stats.worlds[0], ("wrld_x".to_string(), 2, 1));
+        assert_eq!(stats.worlds[1], ("wrld_y".to_string(), 1, 1));
+
+        assert!(store.user_stats("usr_unknown").unwrap().is_none());
+        assert_eq!(store.all_stats().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_display_name_history() {
+        let (_dir, mut store) = temp_store();
+        store
+            .record_friends(&[observation("wrld_x:1", "android")], 100)
+            .unwrap();
+        let renamed = FriendObservation {
+            display_name: "Alicia".to_string(),
+            ..observation("wrld_x:1", "android")
+        };
+        store
+            .record_friends(std::slice::from_ref(&renamed), 200)
+            .unwrap();
+        store.record_friends(&[renamed], 300).unwrap();
+
+        let names = store.display_names("usr_a").unwrap();
+        assert_eq!(names.len(), 2);
+        assert_eq!(names[0].display_name, "Alicia");
+        assert_eq!(names[0].first_seen, 200);
+        assert_eq!(names[0].last_seen, 300);
+        assert_eq!(names[1].display_name, "Alice");
+
+        let found = store.find_by_display_name("alice").unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].user_id, "usr_a");
+        assert!(store.find_by_display_name("Bob").unwrap().is_empty());
+        assert_eq!(
+            store.latest_display_name("usr_a").unwrap().as_deref(),
+            Some("Alicia")
+        );
+    }
+
+    #[test]
+    fn test_world_samples() {
+        let (_dir, store) = temp_store();
+        store
+            .record_world_sample(&WorldSample::fixture(200, Some(12)))
+            .unwrap();
+        store
+            .record_world_sample(&WorldSample::fixture(100, None))
+            .unwrap();
+
+        let samples = store.world_samples("wrld_x", None).unwrap();
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[0].observed_at, 100);
+        assert_eq!(samples[0].occupants, None);
+        assert_eq!(store.world_samples("wrld_x", Some(150)).unwrap().len(), 1);
+        assert!(store.world_samples("wrld_y", None).unwrap().is_empty());
+        assert_eq!(
+            store
+                .latest_world_sample("wrld_x")
+                .unwrap()
+                .unwrap()
+                .occupants,
+            Some(12)
+        );
+    }
+
+    #[test]
+    fn test_world_sample_csv_row() {
+        assert_eq!(
+            WorldSample::fixture(0, None).to_csv_row(),
+            "wrld_x,\"Cafe, \"\"Night\"\"\",1970-01-01T00:00:00+00:00,,1000,50,3,2024-01-01T00:00:00.000Z"
+        );
+        let plain = WorldSample {
+            world_name: "Cafe".to_string(),
+            ..WorldSample::fixture(0, Some(1))
+        };
+        assert_eq!(
+            WorldSample::CSV_HEADER.split(',').count(),
+            plain.to_csv_row().split(',').count()
+        );
+    }
+
+    #[test]
+    fn test_is_online_location() {
+        assert!(is_online_location("wrld_x:1"));
+        assert!(is_online_location("private"));
+        assert!(!is_online_location("offline"));
+        assert!(!is_online_location(""));
+    }
+}
@@ -11,6 +11,11 @@ pub struct Cli {
 
 #[derive(Subcommand)]
 pub enum Commands {
+    /// Manage short names for users, usable as `@name` wherever a user is expected
+    Alias {
+        #[command(subcommand)]
+        action: AliasAction,
+    },
     /// Authentication management
     Auth {
         #[command(subcommand)]
@@ -21,11 +26,21 @@ pub enum Commands {
         #[command(subcommand)]
         action: FriendsAction,
     },
+    /// Inspect world instances
+    Instances {
+        #[command(subcommand)]
+        action: InstancesAction,
+    },
     /// Send or request invites from friends
     Invite {
         #[command(subcommand)]
         action: InviteAction,
     },
+    /// View and act on notifications (invites, invite requests, friend requests)
+    Notifications {
+        #[command(subcommand)]
+        action: NotificationsAction,
+    },
     /// Manage users
     Users {
         #[command(subcommand)]
@@ -38,6 +53,42 @@ pub enum Commands {
     },
 }
 
+#[derive(Subcommand)]
+pub enum AliasAction {
+    /// Create or replace an alias for a user
+    Set {
+        /// Alias name (a leading @ is ignored)
+        name: String,
+        /// User identifier (display name or user ID)
+        user: String,
+        /// Treat the user identifier as a user ID
+        #[arg(long)]
+        id: bool,
+    },
+    /// List all aliases
+    List {
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Remove an alias
+    Rm {
+        /// Alias name (a leading @ is ignored)
+        name: String,
+    },
+    /// List the users picked in the "which user did you mean?" prompt
+    Remembered {
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Forget the user picked for a display name
+    Forget {
+        /// Display name as it was typed
+        name: String,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum AuthAction {
     /// Set authentication credentials
@@ -97,6 +148,9 @@ pub enum FriendsAction {
         /// Reverse sort order
         #[arg(short = 'r', long)]
         reverse: bool,
+        /// Show entries hidden by the content policy in the config
+        #[arg(long)]
+        show_all: bool,
         /// Show additional details (status, platform, etc.) [DEPRECATED: use -l instead]
         #[arg(short = 'a', long, hide = true)]
         all: bool,
@@ -111,6 +165,9 @@ pub enum FriendsAction {
         /// Use direct user ID instead of resolving display name
         #[arg(long)]
         id: bool,
+        /// Show entries hidden by the content policy in the config
+        #[arg(long)]
+        show_all: bool,
         /// Output in JSON format
         #[arg(long)]
         json: bool,
@@ -139,54 +196,305 @@ pub enum FriendsAction {
         #[arg(long)]
         id: bool,
     },
+    /// Show the recorded state history of a friend
+    History {
+        /// User identifier (display name or user ID)
+        identifier: String,
+        /// Use direct user ID instead of resolving display name
+        #[arg(long)]
+        id: bool,
+        /// Number of history entries to show
+        #[arg(short = 'n', long, default_value = "20")]
+        limit: usize,
+        /// Show entries hidden by the content policy in the config
+        #[arg(long)]
+        show_all: bool,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show statistics from recorded friend history
+    Stats {
+        /// User identifier (display name or user ID); omit for all friends
+        identifier: Option<String>,
+        /// Use direct user ID instead of resolving display name
+        #[arg(long)]
+        id: bool,
+        /// Number of top worlds to show
+        #[arg(short = 'n', long, default_value = "5")]
+        top: usize,
+        /// Show entries hidden by the content policy in the config
+        #[arg(long)]
+        show_all: bool,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Watch friends and notifications for changes and run configured hooks
+    Watch {
+        /// Polling interval in seconds
+        #[arg(short, long, default_value = "30")]
+        interval: u64,
+        /// Output events as JSON lines
+        #[arg(long)]
+        json: bool,
+        /// Do not run hooks from the config file
+        #[arg(long)]
+        no_hooks: bool,
+        /// Do not poll notifications
+        #[arg(long)]
+        no_notifications: bool,
+        /// Show entries hidden by the content policy in the config
+        #[arg(long)]
+        show_all: bool,
+    },
+    /// Join a friend: self-invite to their instance, or request an invite when that fails
+    Join {
+        /// User identifier (display name or user ID)
+        identifier: String,
+        /// Use direct user ID instead of resolving display name
+        #[arg(long)]
+        id: bool,
+        /// Force traditional invite request (disable auto location join)
+        #[arg(long)]
+        force_request: bool,
+        /// Show every attempted step
+        #[arg(short = 'v', long)]
+        verbose: bool,
+        /// Output a JSON report of the attempted steps
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum InstancesAction {
+    /// Show details of an instance
+    Get {
+        /// Instance location (wrld_...:instance)
+        location: String,
+        /// Show entries hidden by the content policy in the config
+        #[arg(long)]
+        show_all: bool,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Get the shareable short link of an instance
+    ShortName {
+        /// Instance location (wrld_...:instance)
+        location: String,
+    },
+    /// Create a new instance of a world
+    Create {
+        /// World ID (wrld_...)
+        world_id: String,
+        /// Instance type: public, friends+, friends, invite+, invite, group, group+ or group-public
+        #[arg(short = 't', long = "type", default_value = "invite")]
+        r#type: String,
+        /// Server region
+        #[arg(short, long, default_value = "us", value_parser = ["us", "use", "eu", "jp"])]
+        region: String,
+        /// Group that owns the instance (required for group types)
+        #[arg(short, long)]
+        group: Option<String>,
+        /// Let users queue when the instance is full
+        #[arg(long)]
+        queue: bool,
+        /// Only allow age-verified users
+        #[arg(long)]
+        age_gate: bool,
+        /// Send yourself an invite to the new instance
+        #[arg(long)]
+        invite: bool,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Subcommand)]
 pub enum InviteAction {
-    /// Send an invite to a friend
+    /// Send an invite to a friend, or to many friends at once with --to/--to-favorites/--to-online-friends
     Send {
-        /// User identifier (display name or user ID)
-        user: String,
+        /// User identifier (display name or user ID); omit when using --to options
+        #[arg(required_unless_present_any = ["to", "to_favorites", "to_online_friends"])]
+        user: Option<String>,
         /// Instance ID to invite to
-        instance_id: String,
+        #[arg(required_unless_present_any = ["here", "to", "to_favorites", "to_online_friends"])]
+        instance_id: Option<String>,
+        /// Invite to the instance you are currently in
+        #[arg(long, conflicts_with = "instance_id")]
+        here: bool,
         /// Use direct user ID instead of resolving display name
         #[arg(long)]
         id: bool,
-        /// Message slot (0-7)
-        #[arg(short = 'm', long)]
+        /// Message slot (0-11)
+        #[arg(short = 'm', long, value_parser = clap::value_parser!(i32).range(0..=11))]
         message_slot: Option<i32>,
+        /// Also invite these users (comma-separated display names, user IDs or @aliases)
+        #[arg(long, value_delimiter = ',')]
+        to: Vec<String>,
+        /// Also invite everyone in this friend favorite group
+        #[arg(long, value_name = "GROUP")]
+        to_favorites: Option<String>,
+        /// Also invite online friends matching a filter, e.g. "status=joinme|active,platform=pc" or "all"
+        #[arg(long, value_name = "FILTER")]
+        to_online_friends: Option<String>,
+        /// Maximum number of invites sent at the same time
+        #[arg(long, default_value = "3")]
+        concurrency: usize,
     },
     /// Request an invite from a friend
     Request {
         /// User identifier (display name or user ID)
-        user: String,
+        #[arg(required_unless_present = "random", conflicts_with = "random")]
+        user: Option<String>,
+        /// Pick a random online friend in a joinable instance
+        #[arg(long)]
+        random: bool,
+        /// Only pick friends matching this filter, e.g. "status=joinme|active,platform=pc"
+        #[arg(long, requires = "random", value_name = "FILTER")]
+        filter: Option<String>,
+        /// Make friends with Join Me status more likely to be picked
+        #[arg(long, requires = "random")]
+        prefer_join_me: bool,
+        /// Make favorited friends more likely to be picked
+        #[arg(long, requires = "random")]
+        prefer_favorites: bool,
         /// Use direct user ID instead of resolving display name
         #[arg(long)]
         id: bool,
-        /// Message slot (0-7)
-        #[arg(short = 'm', long)]
+        /// Request message slot (0-11, see `invite messages list --type request`)
+        #[arg(short = 'm', long, value_parser = clap::value_parser!(i32).range(0..=11))]
         message_slot: Option<i32>,
         /// Force traditional invite request (disable auto location join)
         #[arg(long)]
         force_request: bool,
+        /// Show every attempted step
+        #[arg(short = 'v', long)]
+        verbose: bool,
+        /// Output a JSON report of the attempted steps
+        #[arg(long)]
+        json: bool,
+    },
+    /// Answer a friend's invite request by inviting them to your current location
+    Respond {
+        /// Notification ID of the invite request, or the user who sent it
+        target: String,
+        /// Use direct user ID instead of resolving display name
+        #[arg(long)]
+        id: bool,
+        /// Message slot for the invite (0-11)
+        #[arg(short = 'm', long, conflicts_with_all = ["slot", "decline"], value_parser = clap::value_parser!(i32).range(0..=11))]
+        message_slot: Option<i32>,
+        /// Reply with this response message slot (0-11) instead of inviting
+        #[arg(short = 's', long, value_parser = clap::value_parser!(i32).range(0..=11))]
+        slot: Option<i32>,
+        /// Decline the request by hiding it; add --slot to also send a response message
+        #[arg(long)]
+        decline: bool,
+    },
+    /// View or edit the messages used in invite message slots
+    Messages {
+        #[command(subcommand)]
+        action: InviteMessagesAction,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum InviteMessagesAction {
+    /// List message slots
+    List {
+        /// Message collection (message, response, request, requestResponse); all when omitted
+        #[arg(short = 't', long = "type")]
+        r#type: Option<String>,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Change the text of a message slot (a slot can be edited once every 60 minutes)
+    Set {
+        /// Slot number (0-11)
+        #[arg(value_parser = clap::value_parser!(i32).range(0..=11))]
+        slot: i32,
+        /// New message text
+        text: String,
+        /// Message collection (message, response, request, requestResponse)
+        #[arg(short = 't', long = "type", default_value = "message")]
+        r#type: String,
     },
 }
 
+#[derive(Subcommand)]
+pub enum NotificationsAction {
+    /// List notifications
+    List {
+        /// Only show these types (comma-separated, e.g. invite,requestInvite,friendRequest)
+        #[arg(short = 't', long = "type", value_delimiter = ',')]
+        r#type: Vec<String>,
+        /// Only show notifications that have not been seen
+        #[arg(long)]
+        unseen: bool,
+        /// Number of notifications to fetch
+        #[arg(short = 'n', long, default_value = "100")]
+        limit: i32,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+        /// Show detailed information
+        #[arg(short = 'l', long)]
+        long: bool,
+    },
+    /// Accept a friend request or an invite
+    Accept {
+        /// Notification ID
+        notification_id: String,
+    },
+    /// Reply to an invite or invite request with a response message
+    Respond {
+        /// Notification ID
+        notification_id: String,
+        /// Response message slot (0-11)
+        #[arg(short = 's', long, value_parser = clap::value_parser!(i32).range(0..=11))]
+        slot: i32,
+    },
+    /// Hide a notification
+    Hide {
+        /// Notification ID
+        notification_id: String,
+    },
+    /// Clear all notifications
+    Clear,
+}
+
 #[derive(Subcommand)]
 pub enum UsersAction {
     /// Search users by display name
     Search {
         /// Search query (display name)
         query: String,
-        /// Number of results to return
-        #[arg(short = 'n', long, default_value = "20")]
+        /// Number of results to return (--all and --max fetch pages of 100)
+        #[arg(short = 'n', long, conflicts_with_all = ["all", "max"], default_value = "20")]
         limit: i32,
         /// Offset for pagination
         #[arg(short, long, default_value = "0")]
         offset: i32,
+        /// Fetch every page of results
+        #[arg(long)]
+        all: bool,
+        /// Fetch pages until this many results were collected
+        #[arg(long, value_name = "N")]
+        max: Option<usize>,
         /// Developer type filter (none, internal)
         #[arg(long)]
         developer_type: Option<String>,
+        /// Sort results by: name, id, activity, joined
+        #[arg(short = 's', long)]
+        sort: Option<String>,
+        /// Reverse sort order
+        #[arg(short = 'r', long)]
+        reverse: bool,
         /// Output in JSON format
         #[arg(long)]
         json: bool,
@@ -226,6 +534,18 @@ pub enum UsersAction {
     },
     /// List all notes
     Notes {
+        /// Sort notes by: name, id, created
+        #[arg(short = 's', long)]
+        sort: Option<String>,
+        /// Reverse sort order
+        #[arg(short = 'r', long)]
+        reverse: bool,
+        /// Fetch every page of results
+        #[arg(long)]
+        all: bool,
+        /// Fetch pages until this many results were collected
+        #[arg(long, value_name = "N")]
+        max: Option<usize>,
         /// Output in JSON format
         #[arg(long)]
         json: bool,
@@ -244,6 +564,17 @@ pub enum UsersAction {
         #[arg(long)]
         json: bool,
     },
+    /// Show display names observed for a user over time
+    Names {
+        /// User identifier (display name or user ID)
+        identifier: String,
+        /// Use direct user ID instead of resolving display name
+        #[arg(long)]
+        id: bool,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
     /// Diagnose user access issues
     Diagnose {
         /// User identifier (display name or user ID)
@@ -254,18 +585,73 @@ pub enum UsersAction {
     },
 }
 
+// Parsed once per run, so the size of the search filters does not matter
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand)]
 pub enum WorldsAction {
     /// Search worlds
     Search {
-        /// Search query
-        query: String,
-        /// Number of results to return
-        #[arg(short = 'n', long, default_value = "20")]
+        /// Search query (optional when filtering by tag, author or platform)
+        query: Option<String>,
+        /// Number of results to return (--all and --max fetch pages of 100)
+        #[arg(short = 'n', long, conflicts_with_all = ["all", "max"], default_value = "20")]
         limit: i32,
         /// Offset for pagination
         #[arg(short, long, default_value = "0")]
         offset: i32,
+        /// Fetch every page of results
+        #[arg(long)]
+        all: bool,
+        /// Fetch pages until this many results were collected
+        #[arg(long, value_name = "N")]
+        max: Option<usize>,
+        /// Sort by name, id, visits, favorites, capacity, occupants or updated (sorted locally),
+        /// or by popularity, heat, trust, shuffle, random, publicationDate, labsPublicationDate,
+        /// created or order (sorted by the API)
+        #[arg(short = 's', long)]
+        sort: Option<String>,
+        /// Reverse the order of the results
+        #[arg(short = 'r', long)]
+        reverse: bool,
+        /// Sort order
+        #[arg(long, value_parser = ["ascending", "descending"])]
+        order: Option<String>,
+        /// Only worlds with all of these tags (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        tag: Vec<String>,
+        /// Exclude worlds with any of these tags (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        exclude_tag: Vec<String>,
+        /// Only worlds with a build for this platform (e.g. android, standalonewindows, ios)
+        #[arg(long)]
+        platform: Option<String>,
+        /// Only featured worlds
+        #[arg(long)]
+        featured: bool,
+        /// Only worlds by this author (display name or user ID)
+        #[arg(long)]
+        author: Option<String>,
+        /// Use fuzzy matching for the query
+        #[arg(long)]
+        fuzzy: bool,
+        /// Release status
+        #[arg(long, value_parser = ["public", "private", "hidden", "all"])]
+        release_status: Option<String>,
+        /// Minimum Unity version the world was built with
+        #[arg(long)]
+        min_unity_version: Option<String>,
+        /// Maximum Unity version the world was built with
+        #[arg(long)]
+        max_unity_version: Option<String>,
+        /// Only keep worlds with this tag; friendly names like "Horror" or "Quest" work (repeatable)
+        #[arg(long, value_name = "TAG")]
+        has_tag: Vec<String>,
+        /// Drop worlds with this tag; friendly names work (repeatable)
+        #[arg(long, value_name = "TAG")]
+        lacks_tag: Vec<String>,
+        /// Show entries hidden by the content policy in the config
+        #[arg(long)]
+        show_all: bool,
         /// Output in JSON format
         #[arg(long)]
         json: bool,
@@ -277,6 +663,225 @@ pub enum WorldsAction {
     Get {
         /// World ID
         world_id: String,
+        /// Also show launch links for the world
+        #[arg(long)]
+        link: bool,
+        /// Show entries hidden by the content policy in the config
+        #[arg(long)]
+        show_all: bool,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// List worlds that currently have the most players
+    Active {
+        /// Number of results to return (--all and --max fetch pages of 100)
+        #[arg(short = 'n', long, conflicts_with_all = ["all", "max"], default_value = "20")]
+        limit: i32,
+        /// Offset for pagination
+        #[arg(short, long, default_value = "0")]
+        offset: i32,
+        /// Fetch every page of results
+        #[arg(long)]
+        all: bool,
+        /// Fetch pages until this many results were collected
+        #[arg(long, value_name = "N")]
+        max: Option<usize>,
+        /// Only keep worlds with this tag; friendly names like "Horror" or "Quest" work (repeatable)
+        #[arg(long, value_name = "TAG")]
+        has_tag: Vec<String>,
+        /// Drop worlds with this tag; friendly names work (repeatable)
+        #[arg(long, value_name = "TAG")]
+        lacks_tag: Vec<String>,
+        /// Show entries hidden by the content policy in the config
+        #[arg(long)]
+        show_all: bool,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+        /// Show detailed information
+        #[arg(short = 'l', long)]
+        long: bool,
+    },
+    /// List worlds you have recently visited
+    Recent {
+        /// Number of results to return (--all and --max fetch pages of 100)
+        #[arg(short = 'n', long, conflicts_with_all = ["all", "max"], default_value = "20")]
+        limit: i32,
+        /// Offset for pagination
+        #[arg(short, long, default_value = "0")]
+        offset: i32,
+        /// Fetch every page of results
+        #[arg(long)]
+        all: bool,
+        /// Fetch pages until this many results were collected
+        #[arg(long, value_name = "N")]
+        max: Option<usize>,
+        /// Only keep worlds with this tag; friendly names like "Horror" or "Quest" work (repeatable)
+        #[arg(long, value_name = "TAG")]
+        has_tag: Vec<String>,
+        /// Drop worlds with this tag; friendly names work (repeatable)
+        #[arg(long, value_name = "TAG")]
+        lacks_tag: Vec<String>,
+        /// Show entries hidden by the content policy in the config
+        #[arg(long)]
+        show_all: bool,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+        /// Show detailed information
+        #[arg(short = 'l', long)]
+        long: bool,
+    },
+    /// List your favorite worlds
+    Favorites {
+        /// Only worlds in this favorite group (name or display name)
+        #[arg(short, long)]
+        group: Option<String>,
+        /// Number of results to return (--all and --max fetch pages of 100)
+        #[arg(short = 'n', long, conflicts_with_all = ["all", "max"], default_value = "100")]
+        limit: i32,
+        /// Offset for pagination
+        #[arg(short, long, default_value = "0")]
+        offset: i32,
+        /// Fetch every page of results
+        #[arg(long)]
+        all: bool,
+        /// Fetch pages until this many results were collected
+        #[arg(long, value_name = "N")]
+        max: Option<usize>,
+        /// Only keep worlds with this tag; friendly names like "Horror" or "Quest" work (repeatable)
+        #[arg(long, value_name = "TAG")]
+        has_tag: Vec<String>,
+        /// Drop worlds with this tag; friendly names work (repeatable)
+        #[arg(long, value_name = "TAG")]
+        lacks_tag: Vec<String>,
+        /// Show entries hidden by the content policy in the config
+        #[arg(long)]
+        show_all: bool,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+        /// Show detailed information
+        #[arg(short = 'l', long)]
+        long: bool,
+    },
+    /// List the public instances of a world with their population and friends inside
+    Instances {
+        /// World ID
+        world_id: String,
+        /// Show entries hidden by the content policy in the config
+        #[arg(long)]
+        show_all: bool,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+        /// Show detailed information
+        #[arg(short = 'l', long)]
+        long: bool,
+    },
+    /// List the worlds you uploaded
+    Mine {
+        /// Only worlds with this release status
+        #[arg(long, value_parser = ["public", "private", "hidden", "all"], default_value = "all")]
+        release_status: String,
+        /// Number of results to return (--all and --max fetch pages of 100)
+        #[arg(short = 'n', long, conflicts_with_all = ["all", "max"], default_value = "100")]
+        limit: i32,
+        /// Offset for pagination
+        #[arg(short, long, default_value = "0")]
+        offset: i32,
+        /// Fetch every page of results
+        #[arg(long)]
+        all: bool,
+        /// Fetch pages until this many results were collected
+        #[arg(long, value_name = "N")]
+        max: Option<usize>,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+        /// Show detailed information
+        #[arg(short = 'l', long)]
+        long: bool,
+    },
+    /// Change the name, description, tags, capacity or release status of one of your worlds
+    Update {
+        /// World ID
+        world_id: String,
+        /// New world name
+        #[arg(long)]
+        name: Option<String>,
+        /// New description
+        #[arg(long)]
+        description: Option<String>,
+        /// Replace the author and content tags; plain words become author_tag_<word> (comma-separated, "" clears them)
+        #[arg(long, value_delimiter = ',')]
+        tags: Option<Vec<String>>,
+        /// New maximum player capacity
+        #[arg(long, value_parser = clap::value_parser!(i32).range(1..))]
+        capacity: Option<i32>,
+        /// New release status
+        #[arg(long, value_parser = ["public", "private"])]
+        release_status: Option<String>,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Publish one of your worlds to Community Labs (once per week)
+    Publish {
+        /// World ID
+        world_id: String,
+    },
+    /// Take one of your worlds out of Community Labs
+    Unpublish {
+        /// World ID
+        world_id: String,
+    },
+    /// Poll worlds, record their statistics and report new versions and player spikes
+    Watch {
+        /// World IDs to watch
+        #[arg(required = true)]
+        world_ids: Vec<String>,
+        /// Polling interval in seconds
+        #[arg(short, long, default_value = "300")]
+        interval: u64,
+        /// Report a spike when the player count grows by at least this many between polls
+        #[arg(long, value_name = "PLAYERS", default_value = "10")]
+        spike: i64,
+        /// Output samples and changes as JSON lines
+        #[arg(long)]
+        json: bool,
+    },
+    /// Export the statistics recorded by `worlds watch` as CSV
+    Export {
+        /// World ID
+        world_id: String,
+        /// Only samples from the last N hours
+        #[arg(long, value_name = "HOURS")]
+        since: Option<u64>,
+        /// Write to this file instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<std::path::PathBuf>,
+    },
+    /// List known world tags and their friendly names
+    Tags {
+        /// Only show one category (author, feature, content, language, system, admin, platform)
+        #[arg(short, long)]
+        category: Option<String>,
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Generate links that open a world or instance in VRChat
+    Link {
+        /// World ID, or a full instance location (wrld_...:instance)
+        world: String,
+        /// Instance ID within the world
+        #[arg(short, long)]
+        instance: Option<String>,
+        /// Open the link: `client` (vrchat://, the default) or `web` (vrchat.com)
+        #[arg(long, value_name = "TARGET", num_args = 0..=1, default_missing_value = "client", value_parser = ["client", "web"])]
+        open: Option<String>,
         /// Output in JSON format
         #[arg(long)]
         json: bool,
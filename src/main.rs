@@ -1,6 +1,7 @@
 mod commands;
 mod common;
 mod config;
+mod history;
 
 use anyhow::Result;
 use clap::Parser;
@@ -20,7 +21,14 @@ async fn main() -> Result<()> {
     let cli = Cli::parse();
 
     let result = match cli.command {
+        Commands::Alias { action } => commands::alias::handle_alias_command(action).await,
         Commands::Friends { action } => commands::friends::handle_friends_command(action).await,
+        Commands::Instances { action } => {
+            commands::instances::handle_instances_command(action).await
+        }
+        Commands::Notifications { action } => {
+            commands::notifications::handle_notifications_command(action).await
+        }
         Commands::Users { action } => commands::users::handle_users_command(action).await,
         Commands::Worlds { action } => commands::worlds::handle_worlds_command(action).await,
         Commands::Auth { action } => commands::auth::handle_auth_command(action).await,
@@ -62,5 +62,35 @@ fn test_worlds_help() {
         .stdout(predicate::str::contains("Manage worlds"));
 }
 
+#[test]
+fn test_alias_help() {
+    let mut cmd = Command::cargo_bin("vrcli").unwrap();
+    cmd.args(["alias", "--help"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Manage short names for users"));
+}
+
+#[test]
+fn test_notifications_help() {
+    let mut cmd = Command::cargo_bin("vrcli").unwrap();
+    cmd.args(["notifications", "--help"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("View and act on notifications"));
+}
+
+#[test]
+fn test_instances_help() {
+    let mut cmd = Command::cargo_bin("vrcli").unwrap();
+    cmd.args(["instances", "--help"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Inspect world instances"));
+}
+
 // Note: Tests that require actual API authentication should be run separately
 // and would require mock servers or test credentials